@@ -0,0 +1,157 @@
+use leptos::prelude::*;
+
+use crate::utils::i18n::{format_number, Language};
+
+// SI prefixes from quetta to quecto
+const SI_PREFIXES: [(f64, &str); 21] = [
+    (1e30, "Q"), (1e27, "R"), (1e24, "Y"), (1e21, "Z"), (1e18, "E"), (1e15, "P"), (1e12, "T"), (1e9, "G"), (1e6, "M"), (1e3, "k"),
+    (1.0, ""),
+    (1e-3, "m"), (1e-6, "µ"), (1e-9, "n"), (1e-12, "p"), (1e-15, "f"), (1e-18, "a"), (1e-21, "z"), (1e-24, "y"), (1e-27, "r"), (1e-30, "q"),
+];
+
+// Format a value with three significant digits and an SI prefix, e.g. 4.2e23 as "420 Y"
+pub fn format_si(value: f64, language: Language) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return format!("{}", value);
+    }
+
+    let (factor, prefix) = SI_PREFIXES.iter().find(|(factor, _)| value.abs() >= *factor).unwrap_or(&SI_PREFIXES[SI_PREFIXES.len() - 1]);
+    let scaled = value / factor;
+    let decimals = if scaled.abs() < 10.0 { 2 } else if scaled.abs() < 100.0 { 1 } else { 0 };
+    if prefix.is_empty() {
+        format_number(language, scaled, decimals)
+    } else {
+        format!("{} {}", format_number(language, scaled, decimals), prefix)
+    }
+}
+
+// Scale of a chart axis and the range set by the user
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct AxisState {
+    pub log: bool,
+    // Automatic range if None
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl AxisState {
+    // Position of a value on the axis, NaN if it is outside of the range or can not be shown logarithmically
+    pub fn scale(&self, value: f64) -> f64 {
+        let outside = self.min.is_some_and(|min| value < min) || self.max.is_some_and(|max| value > max);
+        if outside || (self.log && value <= 0.0) {
+            f64::NAN
+        } else if self.log {
+            value.log10()
+        } else {
+            value
+        }
+    }
+
+    // Whether a value is hidden by the axis settings
+    pub fn is_hidden(&self, value: f64) -> bool {
+        value.is_finite() && self.scale(value).is_nan()
+    }
+
+    // Swap a range entered the wrong way round
+    fn order_range(&mut self) {
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min > max {
+                self.min = Some(max);
+                self.max = Some(min);
+            }
+        }
+    }
+
+    // Range set by the user on the axis
+    pub fn range(&self) -> (Option<f64>, Option<f64>) {
+        (self.min.map(|min| self.scale(min)).filter(|min| !min.is_nan()), self.max.map(|max| self.scale(max)).filter(|max| !max.is_nan()))
+    }
+
+    // Label of a tick at a position on the axis
+    pub fn format_tick(&self, position: f64, language: Language) -> String {
+        if self.log {
+            format_si(10.0_f64.powf(position), language)
+        } else {
+            format_si(position, language)
+        }
+    }
+}
+
+// Point of a chart whose values can be placed on the axes
+pub trait ChartPoint: Clone {
+    // The X value and all Y values of the point
+    fn values_mut(&mut self) -> (&mut f64, Vec<&mut f64>);
+}
+
+// Apply the axis settings, points hidden on the X axis are left out and hidden values become gaps
+pub fn scale_chart_data<T: ChartPoint>(data: &[T], x_axis: AxisState, y_axis: AxisState) -> Vec<T> {
+    data.iter().cloned().filter_map(|mut point| {
+        let (x, y_values) = point.values_mut();
+        *x = x_axis.scale(*x);
+        if x.is_nan() {
+            return None;
+        }
+        for y in y_values {
+            *y = y_axis.scale(*y);
+        }
+        Some(point)
+    }).collect()
+}
+
+// Number of values the axis settings leave out
+pub fn count_hidden_points<T: ChartPoint>(data: &[T], x_axis: AxisState, y_axis: AxisState) -> usize {
+    data.iter().cloned().map(|mut point| {
+        let (x, y_values) = point.values_mut();
+        if x_axis.is_hidden(*x) {
+            y_values.iter().filter(|y| !y.is_nan()).count()
+        } else {
+            y_values.iter().filter(|y| y_axis.is_hidden(***y)).count()
+        }
+    }).sum()
+}
+
+// Toggle between a linear and logarithmic axis and an optional range
+#[component]
+pub fn AxisControls(label: &'static str, axis: (ReadSignal<AxisState>, WriteSignal<AxisState>)) -> impl IntoView {
+    let parse_limit = |ev: &leptos::ev::Event| event_target_value(ev).parse::<f64>().ok();
+
+    view! {
+        <div class="axis_controls">
+            <span>{label}</span>
+            <label>"Logarithmic "
+                <input type="checkbox" prop:checked=move || axis.0.get().log on:change=move |ev| {
+                    let log = event_target_checked(&ev);
+                    axis.1.update(|axis| axis.log = log);
+                } />
+            </label>
+            <label>"Min "
+                <input type="number" placeholder="auto" prop:value=move || axis.0.get().min.map(|min| min.to_string()).unwrap_or_default() on:change=move |ev| {
+                    let min = parse_limit(&ev);
+                    axis.1.update(|axis| {
+                        axis.min = min;
+                        axis.order_range();
+                    });
+                } />
+            </label>
+            <label>"Max "
+                <input type="number" placeholder="auto" prop:value=move || axis.0.get().max.map(|max| max.to_string()).unwrap_or_default() on:change=move |ev| {
+                    let max = parse_limit(&ev);
+                    axis.1.update(|axis| {
+                        axis.max = max;
+                        axis.order_range();
+                    });
+                } />
+            </label>
+        </div>
+    }
+}
+
+// Indicator for points the axis settings leave out
+#[component]
+pub fn ClippingIndicator(hidden_points: Memo<usize>) -> impl IntoView {
+    view! {
+        <Show when=move || { hidden_points.get() > 0 }>
+            <span class="note clipping_indicator">{move || format!("{} points are outside of the set range and not shown", hidden_points.get())}</span>
+        </Show>
+    }
+}
@@ -0,0 +1,164 @@
+use leptos::prelude::*;
+use leptos_chartistry::*;
+use leptos_chartistry::IntoInner;
+use std::f64::consts::PI;
+
+use crate::utils::{gravitational_force_sun::calculate_gravitational_force_with_sun, kepler::calculate_mean_anomaly, orbital_period::calculate_orbital_period, orbital_velocity::{calculate_flight_path_angle, calculate_orbital_velocity, calculate_radial_velocity_component, calculate_tangential_velocity_component}, radius::calculate_radius, stellar_flux::{calculate_equilibrium_temperature, calculate_stellar_flux}, units::UnitSettings};
+use super::{planet::PlanetData, language::translated_title, chart_table::ChartTable, unit_settings::use_unit_settings};
+use super::switch_view::is_view_active;
+
+// Values of the planet read once for all points of a chart
+#[derive(Clone, Copy)]
+struct OrbitParameters {
+    a: f64,
+    e: f64,
+    m_object: f64,
+    m_sun: f64,
+    m_earth: f64,
+    g: f64,
+    l_sun: f64,
+    period: f64,
+}
+
+impl OrbitParameters {
+    fn new(planet: &PlanetData) -> Self {
+        let (a, m_object) = (planet.a.0.get(), planet.m_object.0.get());
+        Self {
+            a,
+            e: planet.e.0.get(),
+            m_object,
+            m_sun: planet.m_sun,
+            m_earth: planet.m_earth,
+            g: planet.g,
+            l_sun: planet.l_sun,
+            period: calculate_orbital_period(a, planet.g, m_object, planet.m_sun, planet.m_earth),
+        }
+    }
+}
+
+// A quantity that can be plotted, evaluated at a true anomaly in the chosen units
+struct Quantity {
+    name: &'static str,
+    // Whether it can be used as the X axis, it has to vary along the whole orbit
+    independent: bool,
+    unit: fn(&UnitSettings) -> &'static str,
+    value: fn(&OrbitParameters, &UnitSettings, f64) -> f64,
+}
+
+impl Quantity {
+    fn label(&self, units: &UnitSettings) -> String {
+        format!("{} ({})", self.name, (self.unit)(units))
+    }
+}
+
+const QUANTITIES: [Quantity; 11] = [
+    Quantity { name: "True anomaly", independent: true, unit: UnitSettings::angle_label, value: |_, units, angle| units.angle(angle) },
+    Quantity { name: "Mean anomaly", independent: true, unit: UnitSettings::angle_label, value: |orbit, units, angle| units.angle(calculate_mean_anomaly(angle, orbit.e).rem_euclid(2.0 * PI)) },
+    Quantity { name: "Time since periapsis", independent: true, unit: UnitSettings::time_label, value: |orbit, units, angle| {
+        units.time(calculate_mean_anomaly(angle, orbit.e).rem_euclid(2.0 * PI) / (2.0 * PI) * orbit.period)
+    } },
+    Quantity { name: "Distance from the star", independent: true, unit: UnitSettings::distance_label, value: |orbit, units, angle| units.distance(calculate_radius(orbit.a, orbit.e, angle)) },
+    Quantity { name: "Orbital speed", independent: false, unit: UnitSettings::speed_label, value: |orbit, units, angle| units.speed(calculate_orbital_velocity(orbit.a, orbit.e, orbit.m_sun, orbit.g, angle)) },
+    Quantity { name: "Radial velocity", independent: false, unit: UnitSettings::speed_label, value: |orbit, units, angle| units.speed(calculate_radial_velocity_component(orbit.a, orbit.e, orbit.m_sun, orbit.g, angle)) },
+    Quantity { name: "Tangential velocity", independent: false, unit: UnitSettings::speed_label, value: |orbit, units, angle| units.speed(calculate_tangential_velocity_component(orbit.a, orbit.e, orbit.m_sun, orbit.g, angle)) },
+    Quantity { name: "Flight path angle", independent: false, unit: UnitSettings::angle_label, value: |orbit, units, angle| units.angle(calculate_flight_path_angle(orbit.e, angle).to_radians()) },
+    Quantity { name: "Gravitational force with the star", independent: false, unit: UnitSettings::force_label, value: |orbit, units, angle| {
+        units.force(calculate_gravitational_force_with_sun(orbit.m_object, orbit.m_sun, orbit.m_earth, orbit.a, orbit.e, orbit.g, angle))
+    } },
+    Quantity { name: "Stellar flux", independent: false, unit: |_| "W/m²", value: |orbit, _, angle| calculate_stellar_flux(orbit.l_sun, calculate_radius(orbit.a, orbit.e, angle)) },
+    Quantity { name: "Equilibrium temperature", independent: false, unit: |_| "K", value: |orbit, _, angle| {
+        calculate_equilibrium_temperature(orbit.l_sun, calculate_radius(orbit.a, orbit.e, angle), 0.0)
+    } },
+];
+
+#[derive(Clone, PartialEq)]
+struct ChartData {
+    x: f64,
+    y: Vec<f64>,
+}
+
+// Sample the selected quantities over one orbit, starting at the periapsis
+fn get_chart_data(orbit: OrbitParameters, units: UnitSettings, x_quantity: usize, y_quantities: &[usize], step: f64) -> Vec<ChartData> {
+    let mut data = Vec::new();
+    let mut angle = 0.0;
+    while angle < 2.0 * PI {
+        data.push(ChartData {
+            x: (QUANTITIES[x_quantity].value)(&orbit, &units, angle),
+            y: y_quantities.iter().map(|&quantity| (QUANTITIES[quantity].value)(&orbit, &units, angle)).collect(),
+        });
+        angle += step;
+    }
+    data
+}
+
+//Chart of any quantities of the orbit against a chosen variable
+#[component]
+pub fn ChartBuilder(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let (x_quantity, set_x_quantity) = signal(0_usize);
+    // Indices into QUANTITIES, one series each
+    let (y_quantities, set_y_quantities) = signal(vec![4_usize]);
+    let units = use_unit_settings();
+
+    let chart_data = Memo::new(move |_| get_chart_data(OrbitParameters::new(&planet.get()), units.get(), x_quantity.get(), &y_quantities.get(), 0.005));
+
+    let active = is_view_active("chart_builder");
+    view! {
+        <div id="chart_builder" class="tool_panel view" class:invisible_element=move || !active.get()>
+            <div class="chart_options">
+                <label>"X axis "
+                    <select on:change=move |ev| set_x_quantity.set(event_target_value(&ev).parse::<usize>().unwrap_or(0))>
+                        {QUANTITIES.iter().enumerate().filter(|(_, quantity)| quantity.independent).map(|(index, quantity)| view! {
+                            <option value={index.to_string()}>{move || quantity.label(&units.get())}</option>
+                        }).collect_view()}
+                    </select>
+                </label>
+            </div>
+            <div class="chart_options quantity_list">
+                {QUANTITIES.iter().enumerate().map(|(index, quantity)| view! {
+                    <label>
+                        <input type="checkbox" prop:checked=move || y_quantities.get().contains(&index) on:change=move |ev| {
+                            let checked = event_target_checked(&ev);
+                            set_y_quantities.update(|quantities| {
+                                quantities.retain(|&quantity| quantity != index);
+                                if checked {
+                                    quantities.push(index);
+                                }
+                            });
+                        } />
+                        {move || quantity.label(&units.get())}
+                    </label>
+                }).collect_view()}
+            </div>
+            // Series depend on the selection, so the chart is rebuilt whenever it changes
+            {move || {
+                let units = units.get();
+                let series = y_quantities.get().iter().enumerate().fold(Series::new(|data: &ChartData| data.x), |series, (position, &quantity)| {
+                    series.line(Line::new(move |data: &ChartData| data.y.get(position).copied().unwrap_or(f64::NAN)).with_name(QUANTITIES[quantity].label(&units)))
+                });
+
+                view! {
+                    <Chart
+                        aspect_ratio=AspectRatio::from_env()
+                        series=series
+                        data=chart_data
+                        top=vec![translated_title("Chart Builder").into(), Legend::middle().into()]
+                        left=vec![TickLabels::default().with_format(|value: &f64, _| format!("{:.3e}", value)).into()]
+                        bottom=vec![TickLabels::aligned_floats().into(), RotatedLabel::end(QUANTITIES[x_quantity.get()].label(&units)).into()]
+                        inner=vec![
+                            AxisMarker::left_edge().into_inner(),
+                            AxisMarker::bottom_edge().into_inner(),
+                            XGridLine::default().into_inner(),
+                            YGridLine::default().into_inner(),
+                            YGuideLine::over_mouse().into_inner(),
+                            XGuideLine::over_data().into_inner(),
+                        ]
+                    />
+                }
+            }}
+            <ChartTable
+                columns=Signal::derive(move || std::iter::once(x_quantity.get()).chain(y_quantities.get()).map(|quantity| QUANTITIES[quantity].label(&units.get())).collect())
+                rows=Signal::derive(move || chart_data.get().iter().map(|data| std::iter::once(data.x).chain(data.y.iter().copied()).collect()).collect())
+            />
+        </div>
+    }
+}
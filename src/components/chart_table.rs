@@ -0,0 +1,53 @@
+use leptos::prelude::*;
+
+use crate::utils::i18n::{format_number, format_scientific, translate, Language};
+use super::language::use_language;
+
+// Rows shown at most, longer data is sampled evenly
+const MAX_ROWS: usize = 25;
+
+fn format_value(value: f64, language: Language) -> String {
+    if value.is_nan() {
+        String::from("–")
+    } else if value != 0.0 && (value.abs() >= 1e5 || value.abs() < 1e-2) {
+        format_scientific(language, value, 3)
+    } else {
+        format_number(language, value, 3)
+    }
+}
+
+//Data of a chart as a table, for screen readers and for reading exact values
+#[component]
+pub fn ChartTable(columns: Signal<Vec<String>>, rows: Signal<Vec<Vec<f64>>>) -> impl IntoView {
+    let language = use_language();
+    let sampled_rows = move || {
+        let rows = rows.get();
+        let step = rows.len().div_ceil(MAX_ROWS).max(1);
+        rows.into_iter().step_by(step).collect::<Vec<_>>()
+    };
+
+    view! {
+        <details class="chart_table">
+            <summary>{move || translate(language.get(), "Data table")}</summary>
+            <div class="table_container">
+                <table>
+                    <thead>
+                        <tr>
+                            {move || columns.get().into_iter().map(|column| view! { <th scope="col">{column}</th> }).collect_view()}
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || {
+                            let language = language.get();
+                            sampled_rows().into_iter().map(|row| view! {
+                                <tr>
+                                    {row.into_iter().map(|value| view! { <td>{format_value(value, language)}</td> }).collect_view()}
+                                </tr>
+                            }).collect_view()
+                        }}
+                    </tbody>
+                </table>
+            </div>
+        </details>
+    }
+}
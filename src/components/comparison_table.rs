@@ -0,0 +1,194 @@
+use leptos::prelude::*;
+use std::f64::consts::PI;
+
+use crate::utils::{csv_export::download_csv, density::calculate_density, escape_velocity::calculate_escape_velocity, gravitational_acceleration::calculate_gravitational_acceleration, imported_presets::load_imported_presets, lift_object::calculate_lift_energy, orbital_period::calculate_orbital_period, orbital_velocity::calculate_orbital_velocity, presets::{load_exoplanet_presets, load_presets, PlanetPreset}, units::{UnitSettings, AU, SOLAR_MASS}, i18n::{fill_template, format_number, format_scientific, translate, Language}};
+use super::{planet::PlanetData, unit_settings::use_unit_settings, language::use_language};
+use super::switch_view::is_view_active;
+
+const GROUPS: [&str; 3] = ["Solar System", "Exoplanets", "Imported"];
+
+// A computed column, its values are given in its unit
+struct Column {
+    name: &'static str,
+    unit: fn(&UnitSettings) -> &'static str,
+    // Values below 0.01 or above 10⁶ use scientific notation instead
+    decimals: usize,
+}
+
+impl Column {
+    fn header(&self, units: &UnitSettings, language: Language) -> String {
+        format!("{} ({})", translate(language, self.name), translate(language, (self.unit)(units)))
+    }
+}
+
+const COLUMNS: [Column; 10] = [
+    Column { name: "Period", unit: UnitSettings::time_label, decimals: 3 },
+    Column { name: "Periapsis", unit: UnitSettings::distance_label, decimals: 3 },
+    Column { name: "Apoapsis", unit: UnitSettings::distance_label, decimals: 3 },
+    Column { name: "Periapsis speed", unit: UnitSettings::speed_label, decimals: 2 },
+    Column { name: "Apoapsis speed", unit: UnitSettings::speed_label, decimals: 2 },
+    Column { name: "Escape velocity", unit: UnitSettings::speed_label, decimals: 2 },
+    Column { name: "Surface gravity", unit: UnitSettings::acceleration_label, decimals: 2 },
+    Column { name: "Density", unit: |_| "kg/m³", decimals: 0 },
+    Column { name: "Lift energy", unit: |_| "J", decimals: 2 },
+    Column { name: "Mass", unit: |_| "Earth masses", decimals: 3 },
+];
+
+#[derive(Clone, PartialEq)]
+struct Row {
+    name: String,
+    group: usize,
+    // One value per column, NaN if unknown
+    values: [f64; 10],
+}
+
+fn get_row(preset: &PlanetPreset, group: usize, planet: &PlanetData, lift_parameters: (f64, f64), units: &UnitSettings) -> Row {
+    let a = preset.a * AU;
+    let m_sun = preset.star.m * SOLAR_MASS;
+    let (m_earth, d_earth, g) = (planet.m_earth, planet.d_earth, planet.g);
+    // Properties of the body itself need its mass
+    let with_mass = |value: f64| if preset.m != 0.0 && preset.d != 0.0 { value } else { f64::NAN };
+
+    Row {
+        name: preset.name.clone(),
+        group,
+        values: [
+            units.time(calculate_orbital_period(a, g, preset.m, m_sun, m_earth)),
+            units.distance(a * (1.0 - preset.e)),
+            units.distance(a * (1.0 + preset.e)),
+            units.speed(calculate_orbital_velocity(a, preset.e, m_sun, g, 0.0)),
+            units.speed(calculate_orbital_velocity(a, preset.e, m_sun, g, PI)),
+            with_mass(units.speed(calculate_escape_velocity(preset.d, d_earth, preset.m, m_earth))),
+            with_mass(units.acceleration(calculate_gravitational_acceleration(preset.d, preset.m, m_earth))),
+            with_mass(calculate_density(preset.d, d_earth, preset.m, m_earth)),
+            with_mass(calculate_lift_energy(preset.d, d_earth, preset.m, m_earth, g, lift_parameters.0, lift_parameters.1 * 1000.0)),
+            if preset.m != 0.0 { preset.m } else { f64::NAN },
+        ],
+    }
+}
+
+fn format_value(value: f64, decimals: usize, language: Language) -> String {
+    if value.is_nan() {
+        String::from("–")
+    } else if value != 0.0 && (value.abs() < 0.01 || value.abs() >= 1_000_000.0) {
+        format_scientific(language, value, 2)
+    } else {
+        format_number(language, value, decimals)
+    }
+}
+
+//Table of all presets with sortable and filterable computed columns
+#[component]
+pub fn ComparisonTable(planet: ReadSignal<PlanetData>, lift_parameters: ReadSignal<(f64, f64)>) -> impl IntoView {
+    let (filter, set_filter) = signal(String::new());
+    // None shows all groups
+    let (group_filter, set_group_filter) = signal(None::<usize>);
+    // Column 0 is the name, the values follow, sorted ascending if true
+    let (sort, set_sort) = signal((0_usize, true));
+    let units = use_unit_settings();
+    let language = use_language();
+
+    let rows = Memo::new(move |_| {
+        let planet = planet.get();
+        let lift_parameters = lift_parameters.get();
+        let units = units.get();
+        // Imported presets are read again with every change, they can be added from the inputs at any time
+        let groups = [load_presets(), load_exoplanet_presets(), load_imported_presets()];
+
+        let filter = filter.get().to_lowercase();
+        let mut rows: Vec<Row> = groups.iter().enumerate()
+            .filter(|(group, _)| group_filter.get().is_none_or(|selected| selected == *group))
+            .flat_map(|(group, presets)| presets.iter().map(move |preset| (group, preset)))
+            .filter(|(_, preset)| preset.name.to_lowercase().contains(&filter))
+            .map(|(group, preset)| get_row(preset, group, &planet, lift_parameters, &units))
+            .collect();
+
+        let (column, ascending) = sort.get();
+        rows.sort_by(|first, second| {
+            let ordering = if column == 0 {
+                first.name.cmp(&second.name)
+            } else {
+                // Unknown values always go to the end
+                let (first, second) = (first.values[column - 1], second.values[column - 1]);
+                match (first.is_nan(), second.is_nan()) {
+                    (true, true) => std::cmp::Ordering::Equal,
+                    (true, false) => return std::cmp::Ordering::Greater,
+                    (false, true) => return std::cmp::Ordering::Less,
+                    (false, false) => first.total_cmp(&second),
+                }
+            };
+            if ascending { ordering } else { ordering.reverse() }
+        });
+        rows
+    });
+
+    let sort_by = move |column: usize| set_sort.update(|(sorted_column, ascending)| {
+        if *sorted_column == column {
+            *ascending = !*ascending;
+        } else {
+            *sorted_column = column;
+            *ascending = true;
+        }
+    });
+    let sort_marker = move |column: usize| match sort.get() {
+        (sorted_column, true) if sorted_column == column => " ▲",
+        (sorted_column, false) if sorted_column == column => " ▼",
+        _ => "",
+    };
+
+    let export = move |_| {
+        let (units, language) = (units.get(), language.get());
+        let mut csv = vec![[translate(language, "Name"), translate(language, "Group")].into_iter().map(String::from).chain(COLUMNS.iter().map(|column| column.header(&units, language))).collect::<Vec<_>>()];
+        csv.extend(rows.get().iter().map(|row| {
+            [row.name.clone(), translate(language, GROUPS[row.group]).to_string()].into_iter()
+                .chain(row.values.iter().map(|value| if value.is_nan() { String::new() } else { value.to_string() }))
+                .collect()
+        }));
+        download_csv("celestial_bodies.csv", &csv);
+    };
+
+    let active = is_view_active("comparison_table");
+    view! {
+        <div id="comparison_table" class="tool_panel view" class:invisible_element=move || !active.get()>
+            <div class="chart_options">
+                <input type="text" placeholder=move || translate(language.get(), "Filter by name") prop:value=move || filter.get() on:input=move |ev| set_filter.set(event_target_value(&ev)) />
+                <select on:change=move |ev| set_group_filter.set(event_target_value(&ev).parse::<usize>().ok())>
+                    <option value="all">{move || translate(language.get(), "All groups")}</option>
+                    {GROUPS.iter().enumerate().map(|(index, &group)| view! {
+                        <option value={index.to_string()}>{move || translate(language.get(), group)}</option>
+                    }).collect_view()}
+                </select>
+                <button on:click=export>{move || translate(language.get(), "Export CSV")}</button>
+            </div>
+            <div class="table_container">
+                <table>
+                    <thead>
+                        <tr>
+                            <th on:click=move |_| sort_by(0)>{move || translate(language.get(), "Name")} {move || sort_marker(0)}</th>
+                            {COLUMNS.iter().enumerate().map(|(index, column)| view! {
+                                <th on:click=move |_| sort_by(index + 1)>{move || column.header(&units.get(), language.get())} {move || sort_marker(index + 1)}</th>
+                            }).collect_view()}
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || rows.get().into_iter().map(|row| view! {
+                            <tr>
+                                <td>{row.name}</td>
+                                {row.values.into_iter().zip(COLUMNS.iter()).map(|(value, column)| view! {
+                                    <td>{move || format_value(value, column.decimals, language.get())}</td>
+                                }).collect_view()}
+                            </tr>
+                        }).collect_view()}
+                    </tbody>
+                </table>
+            </div>
+            <span class="note">{move || {
+                let (units, language, (mass, altitude)) = (units.get(), language.get(), lift_parameters.get());
+                fill_template(
+                    translate(language, "Lift energy for {} kg to {} {}, set in the properties"),
+                    &[format_number(language, mass, 0), format_number(language, units.length(altitude), 0), String::from(units.length_label())],
+                )
+            }}</span>
+        </div>
+    }
+}
@@ -0,0 +1,181 @@
+use leptos::prelude::*;
+use std::f64::consts::PI;
+
+use crate::utils::{delta_v_budget::{load_delta_v_budget, save_delta_v_budget, DeltaVBudget, Maneuver, ManeuverKind, Stage}, escape_velocity::calculate_escape_velocity, orbital_velocity::calculate_orbital_velocity, radius::calculate_radius, rocket_equation::calculate_delta_v, i18n::{fill_template, translate}};
+use super::planet::PlanetData;
+use super::{switch_view::is_view_active, unit_settings::use_unit_settings, language::use_language};
+
+fn get_maneuver_name(kind: ManeuverKind) -> &'static str {
+    match kind {
+        ManeuverKind::Launch => "Launch",
+        ManeuverKind::Transfer => "Transfer",
+        ManeuverKind::Capture => "Capture",
+        ManeuverKind::Landing => "Landing",
+    }
+}
+
+// Δv in km/s of a maneuver at the current object, the transfer and capture form a Hohmann transfer
+// from a circular orbit at the periapsis to a circular orbit at the apoapsis of the current orbit
+fn get_maneuver_delta_v(kind: ManeuverKind, planet: &PlanetData) -> f64 {
+    let (a, e) = (planet.a.0.get_untracked(), planet.e.0.get_untracked());
+    let escape_velocity = calculate_escape_velocity(planet.d.0.get_untracked(), planet.d_earth, planet.m_object.0.get_untracked(), planet.m_earth);
+
+    match kind {
+        ManeuverKind::Launch => escape_velocity,
+        ManeuverKind::Transfer => {
+            let periapsis = calculate_radius(a, e, 0.0);
+            calculate_orbital_velocity(a, e, planet.m_sun, planet.g, 0.0) - calculate_orbital_velocity(periapsis, 0.0, planet.m_sun, planet.g, 0.0)
+        }
+        ManeuverKind::Capture => {
+            let apoapsis = calculate_radius(a, e, PI);
+            calculate_orbital_velocity(apoapsis, 0.0, planet.m_sun, planet.g, 0.0) - calculate_orbital_velocity(a, e, planet.m_sun, planet.g, PI)
+        }
+        // Orbital velocity at the surface of an airless object
+        ManeuverKind::Landing => escape_velocity / f64::sqrt(2.0),
+    }
+}
+
+// Δv in km/s each stage can deliver while carrying the payload and all stages after it, None without any mass left after the burn
+fn get_stage_capacities(budget: &DeltaVBudget) -> Vec<Option<f64>> {
+    budget.stages.iter().enumerate().map(|(index, stage)| {
+        let carried: f64 = budget.payload_mass + budget.stages[index + 1..].iter().map(|stage| stage.dry_mass + stage.propellant_mass).sum::<f64>();
+        let m_final = carried + stage.dry_mass;
+        (m_final > 0.0).then(|| calculate_delta_v(stage.isp, m_final + stage.propellant_mass, m_final) / 1000.0)
+    }).collect()
+}
+
+#[component]
+fn StageInput(label: &'static str, index: usize, budget: ReadSignal<DeltaVBudget>, set_budget: WriteSignal<DeltaVBudget>, field: fn(&mut Stage) -> &mut f64) -> impl IntoView {
+    let language = use_language();
+
+    view! {
+        <label>{move || translate(language.get(), label)}
+            <input type="number" min="0" prop:value=move || budget.with(|budget| budget.stages.get(index).cloned().map(|mut stage| *field(&mut stage))) on:input=move |ev| {
+                let value = event_target_value(&ev).parse::<f64>().unwrap_or(0.0).max(0.0);
+                set_budget.update(|budget| if let Some(stage) = budget.stages.get_mut(index) {
+                    *field(stage) = value;
+                });
+            } />
+        </label>
+    }
+}
+
+//Stages and maneuvers of a mission with the Δv margin of every stage
+#[component]
+pub fn DeltaVBudgetBuilder(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    let units = use_unit_settings();
+    let (budget, set_budget) = signal(load_delta_v_budget());
+    // Only rebuild the rows when stages or maneuvers are added or removed, so inputs keep their focus
+    let stage_count = Memo::new(move |_| budget.with(|budget| budget.stages.len()));
+    let maneuver_count = Memo::new(move |_| budget.with(|budget| budget.maneuvers.len()));
+
+    Effect::new(move |_| save_delta_v_budget(&budget.get()));
+
+    let capacities = Memo::new(move |_| get_stage_capacities(&budget.get()));
+    let assigned = move |index: usize| budget.with(|budget| {
+        budget.maneuvers.iter().filter(|maneuver| maneuver.stage == index).map(|maneuver| maneuver.delta_v).sum::<f64>()
+    });
+
+    let add_maneuver = move |kind: ManeuverKind| {
+        let delta_v = get_maneuver_delta_v(kind, &planet.get_untracked());
+        set_budget.update(|budget| {
+            // Later maneuvers default to the last stage
+            let stage = budget.stages.len().saturating_sub(1);
+            budget.maneuvers.push(Maneuver { kind, delta_v, stage });
+        });
+    };
+
+    let active = is_view_active("delta_v_budget");
+    view! {
+        <div id="delta_v_budget" class="tool_panel view" class:invisible_element=move || !active.get()>
+            <span>{move || translate(language.get(), "Δv budget")}</span>
+            <div class="small_property satellite_inputs">
+                <label>{move || translate(language.get(), "Payload mass (kg): ")}
+                    <input type="number" min="0" prop:value=move || budget.get().payload_mass on:input=move |ev| {
+                        let value = event_target_value(&ev).parse::<f64>().unwrap_or(0.0).max(0.0);
+                        set_budget.update(|budget| budget.payload_mass = value);
+                    } />
+                </label>
+            </div>
+            {move || (0..stage_count.get()).map(|index| view! {
+                <div class="small_property satellite_inputs">
+                    <span>{move || fill_template(translate(language.get(), "Stage {}"), &[(index + 1).to_string()])}</span>
+                    <StageInput label="Dry mass (kg): " index={index} budget={budget} set_budget={set_budget} field={|stage| &mut stage.dry_mass} />
+                    <StageInput label="Propellant mass (kg): " index={index} budget={budget} set_budget={set_budget} field={|stage| &mut stage.propellant_mass} />
+                    <StageInput label="Specific impulse (s): " index={index} budget={budget} set_budget={set_budget} field={|stage| &mut stage.isp} />
+                    <span>{move || {
+                        let Some(capacity) = capacities.get().get(index).copied().flatten() else {
+                            return String::from("Δv –");
+                        };
+                        let margin = capacity - assigned(index);
+                        let speed = |velocity: f64| units.get().format_speed(velocity, language.get());
+                        if margin >= 0.0 {
+                            fill_template(translate(language.get(), "Δv {}, margin {}"), &[speed(capacity), speed(margin)])
+                        } else {
+                            fill_template(translate(language.get(), "Δv {}, short by {}"), &[speed(capacity), speed(-margin)])
+                        }
+                    }}</span>
+                    <button on:click=move |_| set_budget.update(|budget| {
+                        budget.stages.remove(index);
+                        // Later stages move down by one, maneuvers of the removed stage go to the stage taking its place
+                        for maneuver in budget.maneuvers.iter_mut() {
+                            if maneuver.stage > index {
+                                maneuver.stage -= 1;
+                            }
+                            maneuver.stage = maneuver.stage.min(budget.stages.len().saturating_sub(1));
+                        }
+                    })>{move || translate(language.get(), "Remove stage")}</button>
+                </div>
+            }).collect_view()}
+            <button on:click=move |_| set_budget.update(|budget| budget.stages.push(Stage::default()))>{move || translate(language.get(), "Add stage")}</button>
+            {move || (0..maneuver_count.get()).map(|index| view! {
+                <div class="small_property satellite_inputs">
+                    <span>{move || budget.with(|budget| budget.maneuvers.get(index).map(|maneuver| translate(language.get(), get_maneuver_name(maneuver.kind))).unwrap_or_default())}</span>
+                    <label>{move || format!("Δv ({}): ", units.get().speed_label())}
+                        <input type="number" min="0" step="any" prop:value=move || budget.with(|budget| budget.maneuvers.get(index).map(|maneuver| units.get().speed(maneuver.delta_v))) on:input=move |ev| {
+                            let value = units.get_untracked().parse_speed(event_target_value(&ev).parse::<f64>().unwrap_or(0.0).max(0.0));
+                            set_budget.update(|budget| if let Some(maneuver) = budget.maneuvers.get_mut(index) {
+                                maneuver.delta_v = value;
+                            });
+                        } />
+                    </label>
+                    <label>{move || translate(language.get(), "Performed by ")}
+                        <select on:change=move |ev| {
+                            let stage = event_target_value(&ev).parse::<usize>().unwrap_or(0);
+                            set_budget.update(|budget| if let Some(maneuver) = budget.maneuvers.get_mut(index) {
+                                maneuver.stage = stage;
+                            });
+                        }>
+                            {move || (0..stage_count.get()).map(|stage| view! {
+                                <option value={stage.to_string()} selected={budget.with_untracked(|budget| budget.maneuvers.get(index).is_some_and(|maneuver| maneuver.stage == stage))}>{move || fill_template(translate(language.get(), "Stage {}"), &[(stage + 1).to_string()])}</option>
+                            }).collect_view()}
+                        </select>
+                    </label>
+                    <button on:click=move |_| set_budget.update(|budget| {
+                        budget.maneuvers.remove(index);
+                    })>{move || translate(language.get(), "Remove maneuver")}</button>
+                </div>
+            }).collect_view()}
+            <div class="small_property">
+                <span>{move || translate(language.get(), "Add a maneuver at the current object: ")}</span>
+                <button on:click=move |_| add_maneuver(ManeuverKind::Launch)>{move || translate(language.get(), get_maneuver_name(ManeuverKind::Launch))}</button>
+                <button on:click=move |_| add_maneuver(ManeuverKind::Transfer)>{move || translate(language.get(), get_maneuver_name(ManeuverKind::Transfer))}</button>
+                <button on:click=move |_| add_maneuver(ManeuverKind::Capture)>{move || translate(language.get(), get_maneuver_name(ManeuverKind::Capture))}</button>
+                <button on:click=move |_| add_maneuver(ManeuverKind::Landing)>{move || translate(language.get(), get_maneuver_name(ManeuverKind::Landing))}</button>
+            </div>
+            <div class="small_property">
+                <span>{move || {
+                    let required: f64 = budget.get().maneuvers.iter().map(|maneuver| maneuver.delta_v).sum();
+                    let available: f64 = capacities.get().iter().flatten().sum();
+                    let speed = |velocity: f64| units.get().format_speed(velocity, language.get());
+                    fill_template(translate(language.get(), "Required {} of {} available, total margin {}"), &[speed(required), speed(available), speed(available - required)])
+                }}</span>
+            </div>
+            <span class="note">{move || translate(
+                language.get(),
+                "Launch uses the escape velocity, landing the orbital velocity at the surface without atmosphere, transfer and capture a Hohmann transfer between periapsis and apoapsis of the current orbit",
+            )}</span>
+        </div>
+    }
+}
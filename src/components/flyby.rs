@@ -0,0 +1,202 @@
+use leptos::prelude::*;
+
+use crate::utils::{flyby::{calculate_orbit_from_state, calculate_turning_angle, calculate_velocity_vector}, radius::calculate_radius, i18n::{fill_template, format_number, translate, Language}, units::UnitSettings};
+use super::{planet::PlanetData, orbit_visualization_options::VisualizationOptions, unit_settings::use_unit_settings, language::use_language};
+use super::switch_view::is_view_active;
+
+#[derive(Clone, PartialEq, Copy)]
+pub struct FlybyParameters {
+    pub show: (ReadSignal<bool>, WriteSignal<bool>),
+    // Hyperbolic excess velocity relative to the object in km/s
+    pub v_infinity: (ReadSignal<f64>, WriteSignal<f64>),
+    // Altitude of the closest approach above the surface in km
+    pub altitude: (ReadSignal<f64>, WriteSignal<f64>),
+    // Direction of the incoming excess velocity, measured from the velocity of the object in degrees
+    pub approach_angle: (ReadSignal<f64>, WriteSignal<f64>),
+    // Pass behind the object, turning the excess velocity towards its motion
+    pub trailing_side: (ReadSignal<bool>, WriteSignal<bool>),
+    // Angle of the object from its periapsis at the encounter in degrees
+    pub position: (ReadSignal<f64>, WriteSignal<f64>),
+}
+
+impl FlybyParameters {
+    pub fn new() -> Self {
+        let (show, set_show) = signal(false);
+        let (v_infinity, set_v_infinity) = signal(5.0);
+        let (altitude, set_altitude) = signal(1000.0);
+        let (approach_angle, set_approach_angle) = signal(90.0);
+        let (trailing_side, set_trailing_side) = signal(true);
+        let (position, set_position) = signal(0.0);
+        Self {
+            show: (show, set_show),
+            v_infinity: (v_infinity, set_v_infinity),
+            altitude: (altitude, set_altitude),
+            approach_angle: (approach_angle, set_approach_angle),
+            trailing_side: (trailing_side, set_trailing_side),
+            position: (position, set_position),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct FlybyResult {
+    pub turning_angle: f64,
+    // Position of the encounter around the star in m
+    pub position: (f64, f64),
+    pub object_velocity: (f64, f64),
+    pub v_infinity_out: (f64, f64),
+    // Velocities around the star in m/s
+    pub velocity_in: (f64, f64),
+    pub velocity_out: (f64, f64),
+    // Orbits around the star as (semi-latus rectum, eccentricity, angle of the periapsis)
+    pub orbit_in: (f64, f64, f64),
+    pub orbit_out: (f64, f64, f64),
+}
+
+fn rotate(vector: (f64, f64), angle: f64) -> (f64, f64) {
+    (vector.0 * angle.cos() - vector.1 * angle.sin(), vector.0 * angle.sin() + vector.1 * angle.cos())
+}
+
+fn length(vector: (f64, f64)) -> f64 {
+    f64::hypot(vector.0, vector.1)
+}
+
+// Calculate the flyby in the orbital plane, with x pointing to the periapsis of the object
+pub fn get_flyby(planet: &PlanetData, parameters: FlybyParameters) -> FlybyResult {
+    let (a, e) = (planet.a.0.get_untracked(), planet.e.0.get_untracked());
+    let angle = parameters.position.0.get_untracked().to_radians();
+    let radius = calculate_radius(a, e, angle);
+    let position = (radius * angle.cos(), radius * angle.sin());
+    let object_velocity = calculate_velocity_vector(a, e, planet.m_sun, planet.g, angle);
+
+    let v_infinity = parameters.v_infinity.0.get_untracked() * 1000.0;
+    let r_periapsis = planet.d.0.get_untracked() * planet.d_earth * 1000.0 / 2.0 + parameters.altitude.0.get_untracked() * 1000.0;
+    let turning_angle = calculate_turning_angle(v_infinity, r_periapsis, planet.m_object.0.get_untracked() * planet.m_earth, planet.g);
+
+    // Turn towards the velocity of the object when passing behind it, away from it when passing in front
+    let approach_angle = parameters.approach_angle.0.get_untracked().to_radians();
+    let towards_motion = if approach_angle.sin() > 0.0 { -1.0 } else { 1.0 };
+    let direction = if parameters.trailing_side.0.get_untracked() { towards_motion } else { -towards_motion };
+
+    let object_direction = object_velocity.1.atan2(object_velocity.0);
+    let v_infinity_in = rotate((v_infinity, 0.0), object_direction + approach_angle);
+    let v_infinity_out = rotate(v_infinity_in, direction * turning_angle);
+
+    let velocity_in = (object_velocity.0 + v_infinity_in.0, object_velocity.1 + v_infinity_in.1);
+    let velocity_out = (object_velocity.0 + v_infinity_out.0, object_velocity.1 + v_infinity_out.1);
+
+    FlybyResult {
+        turning_angle,
+        position,
+        object_velocity,
+        v_infinity_out,
+        velocity_in,
+        velocity_out,
+        orbit_in: calculate_orbit_from_state(position, velocity_in, planet.m_sun, planet.g),
+        orbit_out: calculate_orbit_from_state(position, velocity_out, planet.m_sun, planet.g),
+    }
+}
+
+// Describe an orbit around the star with its semi-major axis and eccentricity
+fn describe_orbit((semi_latus_rectum, e, _): (f64, f64, f64), units: UnitSettings, language: Language) -> String {
+    if e < 1.0 {
+        format!("a = {}, e = {}", units.format_distance(semi_latus_rectum / (1.0 - e * e), language), format_number(language, e, 3))
+    } else {
+        fill_template(translate(language, "e = {}, escapes the star"), &[format_number(language, e, 3)])
+    }
+}
+
+//Gravity assist of a spacecraft passing the object on its orbit around the star
+#[component]
+pub fn Flyby(planet: ReadSignal<PlanetData>, options: VisualizationOptions) -> impl IntoView {
+    let language = use_language();
+    let units = use_unit_settings();
+    let parameters = options.flyby;
+    let result = Memo::new(move |_| {
+        let planet = planet.get();
+        parameters.v_infinity.0.track();
+        parameters.altitude.0.track();
+        parameters.approach_angle.0.track();
+        parameters.trailing_side.0.track();
+        parameters.position.0.track();
+        get_flyby(&planet, parameters)
+    });
+
+    // Angle of a velocity relative to the velocity of the object
+    let relative_direction = move |velocity: (f64, f64)| {
+        let object_velocity = result.get().object_velocity;
+        let difference = velocity.1.atan2(velocity.0) - object_velocity.1.atan2(object_velocity.0);
+        units.get().format_angle(difference.sin().atan2(difference.cos()), language.get())
+    };
+    let speed = move |velocity: f64| units.get().format_speed(velocity / 1000.0, language.get());
+    // Bounds and steps of the angle inputs, given in degrees
+    let angle = move |degrees: f64| units.get().angle(degrees.to_radians());
+
+    let active = is_view_active("flyby");
+    view! {
+        <div id="flyby" class="tool_panel view" class:invisible_element=move || !active.get()>
+            <span>{move || translate(language.get(), "Gravity assist flyby")}</span>
+            <Show when=move || planet.get().m_object.0.get() != 0.0 && planet.get().d.0.get() != 0.0
+                fallback=move || view!{<div class="small_property">{move || translate(language.get(), "Mass or diameter is undefined")}</div>}>
+                <div class="small_property satellite_inputs">
+                    <label>{move || format!("{} ({}): ", translate(language.get(), "Excess velocity v∞"), translate(language.get(), units.get().speed_label()))}
+                        <input type="number" min=move || units.get().speed(0.1) step="any" prop:value=move || units.get().speed(parameters.v_infinity.0.get()) on:input=move |ev| {
+                            parameters.v_infinity.1.set(units.get_untracked().parse_speed(event_target_value(&ev).parse::<f64>().unwrap_or(0.0)).max(0.1));
+                        } />
+                    </label>
+                    <label>{move || format!("{} ({}): ", translate(language.get(), "Periapsis altitude"), translate(language.get(), units.get().length_label()))}
+                        <input type="number" min="0" step="100" prop:value=move || units.get().length(parameters.altitude.0.get()) on:input=move |ev| {
+                            parameters.altitude.1.set(units.get_untracked().parse_length(event_target_value(&ev).parse::<f64>().unwrap_or(0.0).max(0.0)));
+                        } />
+                    </label>
+                    <label>{move || format!("{} ({}): ", translate(language.get(), "Approach direction from the object's velocity"), translate(language.get(), units.get().angle_label()))}
+                        <input type="number" min=move || angle(-180.0) max=move || angle(180.0) step=move || angle(5.0) prop:value=move || angle(parameters.approach_angle.0.get()) on:input=move |ev| {
+                            parameters.approach_angle.1.set(units.get_untracked().parse_angle(event_target_value(&ev).parse::<f64>().unwrap_or(0.0)).to_degrees());
+                        } />
+                    </label>
+                    <label>{move || format!("{} ({}): ", translate(language.get(), "Object's angle from periapsis"), translate(language.get(), units.get().angle_label()))}
+                        <input type="number" min="0" max=move || angle(360.0) step=move || angle(5.0) prop:value=move || angle(parameters.position.0.get()) on:input=move |ev| {
+                            parameters.position.1.set(units.get_untracked().parse_angle(event_target_value(&ev).parse::<f64>().unwrap_or(0.0)).to_degrees());
+                        } />
+                    </label>
+                    <label>{move || translate(language.get(), "Pass behind the object ")}
+                        <input type="checkbox" prop:checked=move || parameters.trailing_side.0.get() on:change=move |ev| {
+                            parameters.trailing_side.1.set(event_target_checked(&ev));
+                        } />
+                    </label>
+                    <label>{move || translate(language.get(), "Show on the orbit visualization ")}
+                        <input type="checkbox" prop:checked=move || parameters.show.0.get() on:change=move |ev| {
+                            parameters.show.1.set(event_target_checked(&ev));
+                        } />
+                    </label>
+                </div>
+                <div class="small_property">
+                    <span>{move || translate(language.get(), "Turning angle: ")} {move || units.get().format_angle(result.get().turning_angle, language.get())}</span>
+                    <br/>
+                    <span>{move || fill_template(
+                        translate(language.get(), "Outgoing v∞: {} at {} from the object's velocity"),
+                        &[speed(length(result.get().v_infinity_out)), relative_direction(result.get().v_infinity_out)],
+                    )}</span>
+                    <br/>
+                    <span>{move || fill_template(
+                        translate(language.get(), "Velocity around the star: {} before, {} after ({} from the object's velocity)"),
+                        &[speed(length(result.get().velocity_in)), speed(length(result.get().velocity_out)), relative_direction(result.get().velocity_out)],
+                    )}</span>
+                    <br/>
+                    <span>{move || fill_template(
+                        translate(language.get(), "Speed gained: {} (Δv {})"),
+                        &[
+                            speed(length(result.get().velocity_out) - length(result.get().velocity_in)),
+                            speed(length((result.get().velocity_out.0 - result.get().velocity_in.0, result.get().velocity_out.1 - result.get().velocity_in.1))),
+                        ],
+                    )}</span>
+                    <br/>
+                    <span>{move || translate(language.get(), "Orbit before: ")} {move || describe_orbit(result.get().orbit_in, units.get(), language.get())}</span>
+                    <br/>
+                    <span>{move || translate(language.get(), "Orbit after: ")} {move || describe_orbit(result.get().orbit_out, units.get(), language.get())}</span>
+                </div>
+                <span class="note">{move || translate(language.get(), "Planar patched conics, the flyby itself takes no time")}</span>
+            </Show>
+        </div>
+    }
+}
@@ -3,18 +3,26 @@ use leptos_chartistry::*;
 use leptos_chartistry::IntoInner;
 use std::f64::consts::PI;
 
-use crate::utils::gravitational_force_sun::calculate_gravitational_force_with_sun;
-use super::planet::PlanetData;
+use crate::utils::{gravitational_force_sun::calculate_gravitational_force_with_sun, i18n::translate};
+use super::{chart_axes::{count_hidden_points, scale_chart_data, AxisControls, AxisState, ChartPoint, ClippingIndicator}, planet::PlanetData, orbit_visualization_options::ComparisonBody, unit_settings::use_unit_settings, language::{translated_title, use_language}, chart_table::ChartTable};
+use super::switch_view::is_view_active;
 
 #[derive(Clone, PartialEq)]
 struct ChartData {
     x: f64,
-    y: f64
+    y: f64,
+    y_comparison: f64
 }
 
 impl ChartData {
     fn add(x: f64, y: f64) -> Self {
-        Self { x, y }
+        Self { x, y, y_comparison: f64::NAN }
+    }
+}
+
+impl ChartPoint for ChartData {
+    fn values_mut(&mut self) -> (&mut f64, Vec<&mut f64>) {
+        (&mut self.x, vec![&mut self.y, &mut self.y_comparison])
     }
 }
 
@@ -22,19 +30,18 @@ fn get_chart_data(m_object_earth_ratio: f64, m_sun: f64, m_earth: f64, a: f64, e
     let mut data = Vec::new();
     let mut angle = start;
     while angle < end {
-        let force = calculate_gravitational_force_with_sun(m_object_earth_ratio, m_sun, m_earth, a, e, g, angle);
-        if force < 4.6 * 10.0_f64.powi(23) {
-            data.push(ChartData::add(angle, force));
-        }
+        data.push(ChartData::add(angle, calculate_gravitational_force_with_sun(m_object_earth_ratio, m_sun, m_earth, a, e, g, angle)));
         angle += step;
     }
     data
 }
 
 #[component]
-pub fn GravitationalForceWithSunChart(planet: ReadSignal<PlanetData>) -> impl IntoView {
+pub fn GravitationalForceWithSunChart(planet: ReadSignal<PlanetData>, comparison: ReadSignal<Option<ComparisonBody>>) -> impl IntoView {
+    let units = use_unit_settings();
+    let language = use_language();
     let chart_data = Memo::new(move |_| {
-        get_chart_data(
+        let mut data = get_chart_data(
             planet.get().m_object.0.get(),
             planet.get().m_sun,
             planet.get().m_earth,
@@ -44,34 +51,97 @@ pub fn GravitationalForceWithSunChart(planet: ReadSignal<PlanetData>) -> impl In
             0.0,
             2.0 * PI,
             0.01,
-        )
+        );
+        if let Some(comparison) = comparison.get() {
+            let comparison = comparison.planet;
+            let comparison_data = get_chart_data(
+                comparison.m_object.0.get(),
+                comparison.m_sun,
+                comparison.m_earth,
+                comparison.a.0.get(),
+                comparison.e.0.get(),
+                comparison.g,
+                0.0,
+                2.0 * PI,
+                0.01,
+            );
+            for (point, comparison_point) in data.iter_mut().zip(comparison_data) {
+                point.y_comparison = comparison_point.y;
+            }
+        }
+        let units = units.get();
+        data.into_iter().map(|point| ChartData { x: units.angle(point.x), y: units.force(point.y), y_comparison: units.force(point.y_comparison) }).collect::<Vec<_>>()
     });
+    let comparison_name = Memo::new(move |_| comparison.get().map(|comparison| comparison.name));
 
-    let series = Series::new(|data: &ChartData| data.x)
-        .line(Line::new(|data: &ChartData| data.y).with_name("Force (N):"))
-        .with_y_range(0.0, 4.6 * 10.0_f64.powi(23))
-        .with_x_range(0.0, 6.3);
+    let (x_axis, set_x_axis) = signal(AxisState::default());
+    let (y_axis, set_y_axis) = signal(AxisState::default());
+    let scaled_data = Memo::new(move |_| scale_chart_data(&chart_data.get(), x_axis.get(), y_axis.get()));
+    let hidden_points = Memo::new(move |_| count_hidden_points(&chart_data.get(), x_axis.get(), y_axis.get()));
 
+    let table_columns = Signal::derive(move || {
+        let (units, language) = (units.get(), language.get());
+        let mut columns = vec![
+            format!("{} ({})", translate(language, "True anomaly"), translate(language, units.angle_label())),
+            format!("{} ({})", translate(language, "Force"), units.force_label()),
+        ];
+        if let Some(name) = comparison_name.get() {
+            columns.push(format!("{} ({})", name, units.force_label()));
+        }
+        columns
+    });
+    let table_rows = Signal::derive(move || {
+        let with_comparison = comparison_name.get().is_some();
+        chart_data.get().iter().map(|data| if with_comparison { vec![data.x, data.y, data.y_comparison] } else { vec![data.x, data.y] }).collect()
+    });
+
+    let active = is_view_active("gravitational_force_with_sun_chart");
     view! {
-        <div id="gravitational_force_with_sun_chart" class="invisible_element">
+        <div id="gravitational_force_with_sun_chart" class="view" class:invisible_element=move || !active.get()>
             <Show when=move || planet.get().m_object.0.get() != 0.0
                 fallback=|| view!{<div class="small_property">"Mass is undefined"</div>}>
-                <Chart
-                    aspect_ratio=AspectRatio::from_env()
-                    series=series.clone()
-                    data=chart_data  
-                    top=RotatedLabel::middle("Gravitational Force with Sun")
-                    left=vec![RotatedLabel::end("Force (N)").into(), TickLabels::default().with_format(|value: &f64, _| format!("{:.2e}", value)).into()]
-                    bottom=vec![TickLabels::aligned_floats().into(), RotatedLabel::end("Radius (Rad)").into()]
-                    inner=vec![
-                        AxisMarker::left_edge().into_inner(),
-                        AxisMarker::bottom_edge().into_inner(),
-                        XGridLine::default().into_inner(),
-                        YGridLine::default().into_inner(),
-                        YGuideLine::over_mouse().into_inner(),
-                        XGuideLine::over_data().into_inner(),
-                    ]
-                />
+                <div class="chart_options">
+                    <AxisControls label="X axis" axis=(x_axis, set_x_axis) />
+                    <AxisControls label="Y axis" axis=(y_axis, set_y_axis) />
+                    <ClippingIndicator hidden_points=hidden_points />
+                </div>
+                // Rebuild the chart when a comparison is added or removed or the axes change
+                {move || {
+                    let (x_axis, y_axis, language) = (x_axis.get(), y_axis.get(), language.get());
+                    let ((min_x, max_x), (min_y, max_y)) = (x_axis.range(), y_axis.range());
+                    let units = units.get();
+                    let mut series = Series::new(|data: &ChartData| data.x)
+                        .line(Line::new(|data: &ChartData| data.y).with_name(format!("{} ({}):", translate(language, "Force"), units.force_label())))
+                        .with_min_x(min_x)
+                        .with_max_x(max_x)
+                        .with_min_y(min_y)
+                        .with_max_y(max_y);
+                    let mut top = vec![translated_title("Gravitational Force with Sun").into()];
+                    if let Some(name) = comparison_name.get() {
+                        series = series.line(Line::new(|data: &ChartData| data.y_comparison).with_name(format!("{} ({}):", name, units.force_label())));
+                        top.push(Legend::middle().into());
+                    }
+
+                    view! {
+                        <Chart
+                            aspect_ratio=AspectRatio::from_env()
+                            series=series
+                            data=scaled_data
+                            top=top
+                            left=vec![RotatedLabel::end(format!("{} ({})", translate(language, "Force"), units.force_label())).into(), TickLabels::aligned_floats().with_format(move |value, _| y_axis.format_tick(*value, language)).into()]
+                            bottom=vec![TickLabels::aligned_floats().with_format(move |value, _| x_axis.format_tick(*value, language)).into(), RotatedLabel::end(format!("{} ({})", translate(language, "True anomaly"), translate(language, units.angle_label()))).into()]
+                            inner=vec![
+                                AxisMarker::left_edge().into_inner(),
+                                AxisMarker::bottom_edge().into_inner(),
+                                XGridLine::default().into_inner(),
+                                YGridLine::default().into_inner(),
+                                YGuideLine::over_mouse().into_inner(),
+                                XGuideLine::over_data().into_inner(),
+                            ]
+                        />
+                    }
+                }}
+                <ChartTable columns=table_columns rows=table_rows />
             </Show>
         </div>
     }
@@ -0,0 +1,181 @@
+use leptos::prelude::*;
+use leptos_chartistry::*;
+use leptos_chartistry::IntoInner;
+
+use crate::utils::{gravity_profile::{calculate_acceleration_at_distance, calculate_escape_velocity_at_distance, calculate_potential_at_distance}, hill_radius::calculate_hill_radius, launch_delta_v::calculate_circular_velocity, presets::{load_presets, PlanetPreset}, units::{UnitSettings, AU, SOLAR_MASS}};
+use super::{planet::PlanetData, language::translated_title, chart_table::ChartTable, unit_settings::use_unit_settings};
+use super::switch_view::is_view_active;
+
+// Quantities offered by the select as (name, axis label)
+const QUANTITIES: [(&str, &str); 4] = [
+    ("Gravitational acceleration", "Acceleration"),
+    ("Gravitational potential", "Potential"),
+    ("Escape velocity", "Escape Velocity"),
+    ("Circular orbit velocity", "Circular Velocity"),
+];
+
+// Axis label of a quantity with its unit
+fn get_axis_label(quantity: usize, units: UnitSettings) -> String {
+    let unit = match quantity {
+        0 => units.acceleration_label(),
+        1 => "MJ/kg",
+        _ => units.speed_label(),
+    };
+    format!("{} ({})", QUANTITIES[quantity].1, unit)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Body {
+    // Radius, mass and Hill radius in SI units
+    radius: f64,
+    mass: f64,
+    hill_radius: f64,
+}
+
+impl Body {
+    fn from_planet(planet: &PlanetData) -> Self {
+        let mass = planet.m_object.0.get() * planet.m_earth;
+        Self {
+            radius: planet.d.0.get() * planet.d_earth * 1000.0 / 2.0,
+            mass,
+            hill_radius: calculate_hill_radius(planet.a.0.get(), planet.e.0.get(), mass, planet.m_sun),
+        }
+    }
+
+    fn from_preset(preset: &PlanetPreset, planet: &PlanetData) -> Self {
+        let mass = preset.m * planet.m_earth;
+        Self {
+            radius: preset.d * planet.d_earth * 1000.0 / 2.0,
+            mass,
+            hill_radius: calculate_hill_radius(preset.a * AU, preset.e, mass, preset.star.m * SOLAR_MASS),
+        }
+    }
+
+    // Value of the quantity at an altitude in m, none above the Hill radius
+    fn get_value(&self, quantity: usize, altitude: f64, g: f64, units: UnitSettings) -> f64 {
+        let r = self.radius + altitude;
+        if r > self.hill_radius {
+            return f64::NAN;
+        }
+
+        match quantity {
+            0 => units.acceleration(calculate_acceleration_at_distance(r, self.mass, g)),
+            1 => calculate_potential_at_distance(r, self.mass, g) / 1_000_000.0,
+            2 => units.speed(calculate_escape_velocity_at_distance(r, self.mass, g) / 1000.0),
+            _ => units.speed(calculate_circular_velocity(r, self.mass, g) / 1000.0),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct ChartData {
+    x: f64,
+    current: f64,
+    earth: f64,
+    comparison: f64,
+}
+
+impl ChartData {
+    fn add(x: f64, current: f64, earth: f64, comparison: f64) -> Self {
+        Self { x, current, earth, comparison }
+    }
+}
+
+// Values from the surface up to the altitude end in km
+fn get_chart_data(bodies: [Body; 3], quantity: usize, g: f64, units: UnitSettings, end: f64, step: f64) -> Vec<ChartData> {
+    let mut data = Vec::new();
+    let mut altitude = 0.0;
+    while altitude <= end {
+        let [current, earth, comparison] = bodies.map(|body| body.get_value(quantity, altitude * 1000.0, g, units));
+        data.push(ChartData::add(units.length(altitude), current, earth, comparison));
+        altitude += step;
+    }
+    data
+}
+
+//Gravity, potential and local escape and orbit velocities from the surface to the Hill radius
+#[component]
+pub fn GravityProfileChart(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let (quantity, set_quantity) = signal(0_usize);
+    // Default comparison with Mars
+    let (comparison, set_comparison) = signal(3_usize);
+    let (to_hill_radius, set_to_hill_radius) = signal(true);
+    let units = use_unit_settings();
+
+    let chart_data = Memo::new(move |_| {
+        let planet = planet.get();
+        let current = Body::from_planet(&planet);
+        let bodies = [current, Body::from_preset(&load_presets()[2], &planet), Body::from_preset(&load_presets()[comparison.get()], &planet)];
+        // Either out to the Hill radius or close to the surface
+        let end = (if to_hill_radius.get() { current.hill_radius - current.radius } else { 9.0 * current.radius }).max(1000.0) / 1000.0;
+        get_chart_data(bodies, quantity.get(), planet.g, units.get(), end, end / 400.0)
+    });
+
+    let y_label = RotatedLabel::end("");
+    let y_label_text = y_label.text;
+    Effect::new(move |_| y_label_text.set(get_axis_label(quantity.get(), units.get())));
+    let x_label = RotatedLabel::end("");
+    let x_label_text = x_label.text;
+    Effect::new(move |_| x_label_text.set(format!("Altitude above the surface ({})", units.get().length_label())));
+
+    let series = Series::new(|data: &ChartData| data.x)
+        .line(Line::new(|data: &ChartData| data.current).with_name("Current object:"))
+        .line(Line::new(|data: &ChartData| data.earth).with_name("Earth:"))
+        .line(Line::new(|data: &ChartData| data.comparison).with_name("Comparison:"));
+
+    let active = is_view_active("gravity_profile_chart");
+    view! {
+        <div id="gravity_profile_chart" class="tool_panel view" class:invisible_element=move || !active.get()>
+            <Show when=move || planet.get().m_object.0.get() != 0.0 && planet.get().d.0.get() != 0.0
+                fallback=|| view!{<div class="small_property">"Mass or diameter is undefined"</div>}>
+                <div class="chart_options">
+                    <select on:change=move |ev| set_quantity.set(event_target_value(&ev).parse::<usize>().unwrap_or(0))>
+                        {QUANTITIES.iter().enumerate().map(|(index, (name, _))| view! {
+                            <option value={index.to_string()}>{*name}</option>
+                        }).collect_view()}
+                    </select>
+                    <label>"Compare with "
+                        <select on:change=move |ev| set_comparison.set(event_target_value(&ev).parse::<usize>().unwrap_or(0))>
+                            {load_presets().iter().enumerate().map(|(index, preset)| view! {
+                                <option value={index.to_string()} selected={index == comparison.get_untracked()}>{preset.name.clone()}</option>
+                            }).collect_view()}
+                        </select>
+                    </label>
+                    <select on:change=move |ev| set_to_hill_radius.set(event_target_value(&ev) == "hill")>
+                        <option value="hill">"Up to the Hill radius"</option>
+                        <option value="surface">"Up to 10 radii"</option>
+                    </select>
+                </div>
+                <Chart
+                    aspect_ratio=AspectRatio::from_env()
+                    series=series.clone()
+                    data=chart_data
+                    top=vec![translated_title("Gravity Profile").into(), Legend::middle().into()]
+                    left=vec![y_label.clone().into(), TickLabels::default().with_format(|value: &f64, _| format!("{:.2e}", value)).into()]
+                    bottom=vec![TickLabels::default().with_format(|value: &f64, _| format!("{:.2e}", value)).into(), x_label.clone().into()]
+                    inner=vec![
+                        AxisMarker::left_edge().into_inner(),
+                        AxisMarker::bottom_edge().into_inner(),
+                        XGridLine::default().into_inner(),
+                        YGridLine::default().into_inner(),
+                        YGuideLine::over_mouse().into_inner(),
+                        XGuideLine::over_data().into_inner(),
+                    ]
+                />
+                <ChartTable
+                    columns=Signal::derive(move || {
+                        let label = get_axis_label(quantity.get(), units.get());
+                        vec![
+                            format!("Altitude above the surface ({})", units.get().length_label()),
+                            format!("Current object: {}", label),
+                            format!("Earth: {}", label),
+                            format!("{}: {}", load_presets()[comparison.get()].name, label),
+                        ]
+                    })
+                    rows=Signal::derive(move || chart_data.get().iter().map(|data| vec![data.x, data.current, data.earth, data.comparison]).collect())
+                />
+                <span class="note">{move || format!("Hill radius of the current object: {:.0} {}", units.get().length(Body::from_planet(&planet.get()).hill_radius / 1000.0), units.get().length_label())}</span>
+            </Show>
+        </div>
+    }
+}
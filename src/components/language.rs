@@ -0,0 +1,45 @@
+use leptos::prelude::*;
+use leptos_chartistry::RotatedLabel;
+
+use crate::utils::i18n::{load_language, save_language, translate, Language};
+
+const LANGUAGES: [(Language, &str); 2] = [(Language::English, "English"), (Language::German, "Deutsch")];
+
+// Share the language with all components, store every change and set it on the document
+pub fn provide_language() {
+    let (language, set_language) = signal(load_language());
+    Effect::new(move |_| {
+        save_language(language.get());
+        if let Some(root) = document().document_element() {
+            root.set_attribute("lang", language.get().code()).unwrap();
+        }
+    });
+    provide_context((language, set_language));
+}
+
+pub fn use_language() -> ReadSignal<Language> {
+    expect_context::<(ReadSignal<Language>, WriteSignal<Language>)>().0
+}
+
+// Chart title that follows the language
+pub fn translated_title(text: &'static str) -> RotatedLabel {
+    let language = use_language();
+    let title = RotatedLabel::middle(text);
+    let title_text = title.text;
+    Effect::new(move |_| title_text.set(String::from(translate(language.get(), text))));
+    title
+}
+
+#[component]
+pub fn LanguageSelect() -> impl IntoView {
+    let (language, set_language) = expect_context::<(ReadSignal<Language>, WriteSignal<Language>)>();
+
+    view! {
+        <span class="input_select_label">{move || translate(language.get(), "Language: ")}</span>
+        <select on:change=move |ev| set_language.set(LANGUAGES[event_target_value(&ev).parse::<usize>().unwrap_or(0)].0)>
+            {LANGUAGES.iter().enumerate().map(|(index, (option, name))| view! {
+                <option value={index.to_string()} selected={*option == language.get_untracked()}>{*name}</option>
+            }).collect_view()}
+        </select>
+    }
+}
@@ -0,0 +1,142 @@
+use leptos::prelude::*;
+
+use crate::utils::{gravitational_acceleration::calculate_gravitational_acceleration, launch_delta_v::{calculate_circular_velocity, calculate_drag_loss, calculate_gravity_loss, calculate_rotation_bonus}, lift_object::calculate_orbit_insertion_delta_v, rocket_equation::{calculate_payload_fraction, calculate_propellant_fraction}, i18n::{fill_template, format_number, translate}};
+use super::planet::PlanetData;
+use super::{switch_view::is_view_active, unit_settings::use_unit_settings, language::use_language};
+
+#[derive(Clone, PartialEq)]
+struct LaunchBudget {
+    circular_velocity: f64,
+    ideal: f64,
+    gravity_loss: f64,
+    drag_loss: f64,
+    rotation_bonus: f64,
+}
+
+impl LaunchBudget {
+    fn total(&self) -> f64 {
+        self.ideal + self.gravity_loss + self.drag_loss - self.rotation_bonus
+    }
+}
+
+// Δv in m/s from the surface into a circular orbit at the altitude in km
+fn get_launch_budget(planet: &PlanetData, altitude: f64, thrust_to_weight: f64, surface_pressure: Option<f64>, latitude: f64) -> LaunchBudget {
+    let (d, m) = (planet.d.0.get(), planet.m_object.0.get());
+    let r = d * planet.d_earth * 1000.0 / 2.0 + altitude * 1000.0;
+    let circular_velocity = calculate_circular_velocity(r, m * planet.m_earth, planet.g);
+    let rotation_period = planet.rotation_period.0.get();
+
+    LaunchBudget {
+        circular_velocity,
+        ideal: calculate_orbit_insertion_delta_v(d, planet.d_earth, m, planet.m_earth, planet.g, altitude * 1000.0),
+        gravity_loss: calculate_gravity_loss(circular_velocity, thrust_to_weight),
+        drag_loss: surface_pressure.map_or(0.0, |pressure| calculate_drag_loss(pressure * 100_000.0, calculate_gravitational_acceleration(d, m, planet.m_earth))),
+        rotation_bonus: if rotation_period != 0.0 { calculate_rotation_bonus(d, planet.d_earth, rotation_period, latitude.to_radians()) } else { 0.0 },
+    }
+}
+
+//Δv from the surface to a low orbit with losses and the propellant needed for it
+#[component]
+pub fn LaunchDeltaV(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    let units = use_unit_settings();
+    let (altitude, set_altitude) = signal(200.0_f64);
+    let (thrust_to_weight, set_thrust_to_weight) = signal(1.5_f64);
+    let (latitude, set_latitude) = signal(0.0_f64);
+    let (with_drag, set_with_drag) = signal(false);
+    // Surface pressure in bar
+    let (surface_pressure, set_surface_pressure) = signal(1.0_f64);
+    let (isp, set_isp) = signal(350.0_f64);
+    let (stages, set_stages) = signal(2_u32);
+    let (structural_ratio, set_structural_ratio) = signal(0.1_f64);
+
+    let budget = Memo::new(move |_| get_launch_budget(
+        &planet.get(),
+        altitude.get(),
+        thrust_to_weight.get(),
+        if with_drag.get() { Some(surface_pressure.get()) } else { None },
+        latitude.get(),
+    ));
+    let speed = move |velocity: f64| units.get().format_speed(velocity / 1000.0, language.get());
+
+    let active = is_view_active("launch_delta_v");
+    view! {
+        <div id="launch_delta_v" class="tool_panel view" class:invisible_element=move || !active.get()>
+            <span>{move || translate(language.get(), "Launch from the surface to a low orbit")}</span>
+            <Show when=move || planet.get().m_object.0.get() != 0.0 && planet.get().d.0.get() != 0.0
+                fallback=move || view!{<div class="small_property">{move || translate(language.get(), "Mass or diameter is undefined")}</div>}>
+                <div class="small_property satellite_inputs">
+                    <label>{move || format!("{} ({}): ", translate(language.get(), "Orbit altitude"), units.get().length_label())}
+                        <input type="number" min="0" step="10" prop:value=move || units.get().length(altitude.get()) on:input=move |ev| {
+                            set_altitude.set(units.get_untracked().parse_length(event_target_value(&ev).parse::<f64>().unwrap_or(0.0).max(0.0)));
+                        } />
+                    </label>
+                    <label>{move || translate(language.get(), "Thrust-to-weight ratio: ")}
+                        <input type="number" min="1.05" step="0.05" prop:value=move || thrust_to_weight.get() on:input=move |ev| {
+                            set_thrust_to_weight.set(event_target_value(&ev).parse::<f64>().unwrap_or(1.5).max(1.05));
+                        } />
+                    </label>
+                    <label>{move || translate(language.get(), "Launch site latitude (°): ")}
+                        <input type="number" min="0" max="90" step="1" prop:value=move || latitude.get() on:input=move |ev| {
+                            set_latitude.set(event_target_value(&ev).parse::<f64>().unwrap_or(0.0).clamp(0.0, 90.0));
+                        } />
+                    </label>
+                    <label>{move || translate(language.get(), "Include atmospheric drag ")}
+                        <input type="checkbox" prop:checked=move || with_drag.get() on:change=move |ev| {
+                            set_with_drag.set(event_target_checked(&ev));
+                        } />
+                    </label>
+                    <label>{move || translate(language.get(), "Surface pressure (bar): ")}
+                        <input type="number" min="0" step="0.1" prop:value=move || surface_pressure.get() prop:disabled=move || !with_drag.get() on:input=move |ev| {
+                            set_surface_pressure.set(event_target_value(&ev).parse::<f64>().unwrap_or(0.0).max(0.0));
+                        } />
+                    </label>
+                </div>
+                <div class="small_property">
+                    <span>{move || translate(language.get(), "Circular orbit velocity: ")} {move || speed(budget.get().circular_velocity)}</span>
+                    <br/>
+                    <span>{move || translate(language.get(), "Ideal Δv including the climb: ")} {move || speed(budget.get().ideal)}</span>
+                    <br/>
+                    <span>{move || fill_template(translate(language.get(), "Gravity loss: {}, drag loss: {}"), &[speed(budget.get().gravity_loss), speed(budget.get().drag_loss)])}</span>
+                    <br/>
+                    <span>{move || if planet.get().rotation_period.0.get() != 0.0 {
+                        fill_template(translate(language.get(), "Rotation bonus for an eastward launch: {}"), &[speed(budget.get().rotation_bonus)])
+                    } else {
+                        String::from(translate(language.get(), "Rotation bonus is missing, due to the rotation period being unknown"))
+                    }}</span>
+                    <br/>
+                    <span>{move || translate(language.get(), "Total Δv to orbit: ")} {move || speed(budget.get().total())}</span>
+                </div>
+                <div class="small_property satellite_inputs">
+                    <label>{move || translate(language.get(), "Specific impulse (s): ")}
+                        <input type="number" min="1" step="10" prop:value=move || isp.get() on:input=move |ev| {
+                            set_isp.set(event_target_value(&ev).parse::<f64>().unwrap_or(350.0).max(1.0));
+                        } />
+                    </label>
+                    <label>{move || translate(language.get(), "Stages: ")}
+                        <input type="number" min="1" max="5" step="1" prop:value=move || stages.get() on:input=move |ev| {
+                            set_stages.set(event_target_value(&ev).parse::<u32>().unwrap_or(1).clamp(1, 5));
+                        } />
+                    </label>
+                    <label>{move || translate(language.get(), "Dry mass per stage mass without payload: ")}
+                        <input type="number" min="0" max="0.5" step="0.01" prop:value=move || structural_ratio.get() on:input=move |ev| {
+                            set_structural_ratio.set(event_target_value(&ev).parse::<f64>().unwrap_or(0.1).clamp(0.0, 0.5));
+                        } />
+                    </label>
+                </div>
+                <div class="small_property">
+                    <span>{move || translate(language.get(), "Propellant mass fraction: ")} {move || format_number(language.get(), calculate_propellant_fraction(budget.get().total(), isp.get()) * 100.0, 1)} " %"</span>
+                    <br/>
+                    <span>{move || match calculate_payload_fraction(budget.get().total(), isp.get(), structural_ratio.get(), stages.get()) {
+                        fraction if fraction > 0.0 => fill_template(
+                            translate(language.get(), "Payload fraction with {} stages: {} %"),
+                            &[stages.get().to_string(), format_number(language.get(), fraction * 100.0, 2)],
+                        ),
+                        _ => fill_template(translate(language.get(), "Orbit not reachable with {} stages of this dry mass"), &[stages.get().to_string()]),
+                    }}</span>
+                </div>
+                <span class="note">{move || translate(language.get(), "Gravity and drag losses are rough estimates scaled from typical launches on Earth")}</span>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,88 @@
+use leptos::prelude::*;
+use leptos_chartistry::*;
+use leptos_chartistry::IntoInner;
+
+use crate::utils::lift_object::{calculate_lift_energy, calculate_orbit_insertion_energy};
+use super::{planet::PlanetData, language::translated_title, chart_table::ChartTable};
+use super::switch_view::is_view_active;
+
+#[derive(Clone, PartialEq)]
+struct ChartData {
+    x: f64,
+    lift: f64,
+    orbit: f64,
+    lift_earth: f64,
+    orbit_earth: f64,
+}
+
+impl ChartData {
+    fn add(x: f64, lift: f64, orbit: f64, lift_earth: f64, orbit_earth: f64) -> Self {
+        Self { x, lift, orbit, lift_earth, orbit_earth }
+    }
+}
+
+// Energies for the payload from the surface up to the altitude in km, for the object and for Earth
+fn get_chart_data(planet: &PlanetData, m_payload: f64, start: f64, end: f64, step: f64) -> Vec<ChartData> {
+    let (d, m) = (planet.d.0.get(), planet.m_object.0.get());
+
+    let mut data = Vec::new();
+    let mut altitude = start;
+    while altitude <= end {
+        let height = altitude * 1000.0;
+        data.push(ChartData::add(
+            altitude,
+            calculate_lift_energy(d, planet.d_earth, m, planet.m_earth, planet.g, m_payload, height),
+            calculate_orbit_insertion_energy(d, planet.d_earth, m, planet.m_earth, planet.g, m_payload, height),
+            calculate_lift_energy(1.0, planet.d_earth, 1.0, planet.m_earth, planet.g, m_payload, height),
+            calculate_orbit_insertion_energy(1.0, planet.d_earth, 1.0, planet.m_earth, planet.g, m_payload, height),
+        ));
+        altitude += step;
+    }
+    data
+}
+
+#[component]
+pub fn LiftEnergyChart(planet: ReadSignal<PlanetData>, lift_parameters: ReadSignal<(f64, f64)>) -> impl IntoView {
+    let chart_data = Memo::new(move |_| {
+        let (m_payload, altitude) = lift_parameters.get();
+        // Show the way up to twice the selected altitude
+        let end = (2.0 * altitude).max(100.0);
+        get_chart_data(&planet.get(), m_payload, 0.0, end, end / 200.0)
+    });
+
+    let series = Series::new(|data: &ChartData| data.x)
+        .line(Line::new(|data: &ChartData| data.lift).with_name("Lift (J):"))
+        .line(Line::new(|data: &ChartData| data.orbit).with_name("Circular orbit (J):"))
+        .line(Line::new(|data: &ChartData| data.lift_earth).with_name("Lift on Earth (J):"))
+        .line(Line::new(|data: &ChartData| data.orbit_earth).with_name("Circular orbit on Earth (J):"))
+        .with_min_y(0.0);
+
+    let active = is_view_active("lift_energy_chart");
+    view! {
+        <div id="lift_energy_chart" class="view" class:invisible_element=move || !active.get()>
+            <Show when=move || planet.get().m_object.0.get() != 0.0 && planet.get().d.0.get() != 0.0
+                fallback=|| view!{<div class="small_property">"Mass or diameter is undefined"</div>}>
+                <Chart
+                    aspect_ratio=AspectRatio::from_env()
+                    series=series.clone()
+                    data=chart_data
+                    top=vec![translated_title("Energy to lift a Payload and to reach a circular Orbit").into(), Legend::middle().into()]
+                    left=vec![RotatedLabel::end("Energy (J)").into(), TickLabels::default().with_format(|value: &f64, _| format!("{:.2e}", value)).into()]
+                    bottom=vec![TickLabels::aligned_floats().into(), RotatedLabel::end("Altitude (km)").into()]
+                    inner=vec![
+                        AxisMarker::left_edge().into_inner(),
+                        AxisMarker::bottom_edge().into_inner(),
+                        XGridLine::default().into_inner(),
+                        YGridLine::default().into_inner(),
+                        YGuideLine::over_mouse().into_inner(),
+                        XGuideLine::over_data().into_inner(),
+                    ]
+                />
+                <ChartTable
+                    columns=Signal::derive(|| ["Altitude (km)", "Lift (J)", "Circular orbit (J)", "Lift on Earth (J)", "Circular orbit on Earth (J)"].map(String::from).to_vec())
+                    rows=Signal::derive(move || chart_data.get().iter().map(|data| vec![data.x, data.lift, data.orbit, data.lift_earth, data.orbit_earth]).collect())
+                />
+            </Show>
+        </div>
+    }
+}
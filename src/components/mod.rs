@@ -4,4 +4,23 @@ pub mod orbit_visualization;
 pub mod small_properties;
 pub mod gravitational_force_with_sun_chart;
 pub mod orbit_visualization_options;
-pub mod switch_view;
\ No newline at end of file
+pub mod switch_view;
+pub mod synodic_comparison;
+pub mod transit_light_curve_chart;
+pub mod radial_velocity_chart;
+pub mod precession;
+pub mod three_body_view;
+pub mod flyby;
+pub mod lift_energy_chart;
+pub mod launch_delta_v;
+pub mod delta_v_budget;
+pub mod gravity_profile_chart;
+pub mod chart_builder;
+pub mod comparison_table;
+pub mod chart_axes;
+pub mod unit_settings;
+pub mod language;
+pub mod theme;
+pub mod chart_table;
+pub mod quantity_input;
+pub mod orbit_parameters;
\ No newline at end of file
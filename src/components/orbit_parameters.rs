@@ -0,0 +1,157 @@
+use std::f64::consts::PI;
+
+use leptos::prelude::*;
+
+use crate::utils::{i18n::{format_number, translate}, orbit_parameters::{calculate_apoapsis, calculate_periapsis, elements_from_apsides, elements_from_state, semi_major_axis_from_period, semi_major_axis_from_periapsis}, orbital_period::calculate_orbital_period, orbital_velocity::calculate_orbital_velocity, quantity::{format_quantity, parse_quantity, QuantityError, QuantityKind}, units::AU};
+use super::{language::use_language, planet::{update_planet_data, PlanetData}, unit_settings::use_unit_settings};
+
+type Field = (&'static str, QuantityKind, (f64, f64));
+
+// (name, fields) of the ways to define the orbit, the first one are the sliders
+const PARAMETRISATIONS: [(&str, &[Field]); 5] = [
+    ("Semi-major axis and eccentricity", &[]),
+    ("Periapsis and apoapsis", &[
+        ("Periapsis distance", QuantityKind::Distance, (1e-6, f64::INFINITY)),
+        ("Apoapsis distance", QuantityKind::Distance, (1e-6, f64::INFINITY)),
+    ]),
+    ("Period and eccentricity", &[
+        ("Orbital period", QuantityKind::Time, (1e-6, f64::INFINITY)),
+        ("Orbit Eccentricity", QuantityKind::Ratio, (0.0, 0.9999)),
+    ]),
+    ("Periapsis distance q and eccentricity", &[
+        ("Periapsis distance", QuantityKind::Distance, (1e-6, f64::INFINITY)),
+        ("Orbit Eccentricity", QuantityKind::Ratio, (0.0, 0.9999)),
+    ]),
+    ("State vector", &[
+        ("Distance from the star", QuantityKind::Distance, (1e-6, f64::INFINITY)),
+        ("Speed", QuantityKind::Speed, (0.0, f64::INFINITY)),
+        ("Flight path angle", QuantityKind::Angle, (-90.0, 90.0)),
+    ]),
+];
+
+// Values of the fields for the current orbit, the state vector is the one at the periapsis
+fn current_values(planet: PlanetData, parametrisation: usize) -> Vec<f64> {
+    let a = planet.a.0.get_untracked();
+    let e = planet.e.0.get_untracked();
+    match parametrisation {
+        1 => vec![calculate_periapsis(a, e) / AU, calculate_apoapsis(a, e) / AU],
+        2 => vec![calculate_orbital_period(a, planet.g, planet.m_object.0.get_untracked(), planet.m_sun, planet.m_earth), e],
+        3 => vec![calculate_periapsis(a, e) / AU, e],
+        4 => vec![calculate_periapsis(a, e) / AU, calculate_orbital_velocity(a, e, planet.m_sun, planet.g, 0.0), 0.0],
+        _ => vec![],
+    }
+}
+
+// Semi-major axis (m) and eccentricity from the parsed fields, None if they describe no closed orbit
+fn convert(planet: PlanetData, parametrisation: usize, values: &[f64]) -> Option<(f64, f64)> {
+    let (a, e) = match parametrisation {
+        1 => elements_from_apsides(values[0] * AU, values[1] * AU),
+        2 => (semi_major_axis_from_period(values[0], planet.g, planet.m_object.0.get_untracked(), planet.m_sun, planet.m_earth), values[1]),
+        3 => (semi_major_axis_from_periapsis(values[0] * AU, values[1]), values[1]),
+        4 => elements_from_state(values[0] * AU, values[1] * 1000.0, values[2].to_radians(), planet.m_sun, planet.g)?,
+        _ => return None,
+    };
+    (a.is_finite() && a > 0.0 && e < 1.0).then_some((a, e))
+}
+
+// Define the orbit by other parameters than a and e and show all equivalent parametrisations
+#[component]
+pub fn OrbitParameters(planet_signal: (ReadSignal<PlanetData>, WriteSignal<PlanetData>), editable: Signal<bool>) -> impl IntoView {
+    let language = use_language();
+    let units = use_unit_settings();
+    let (parametrisation, set_parametrisation) = signal(0_usize);
+    let (texts, set_texts) = signal(Vec::<String>::new());
+    let (errors, set_errors) = signal(Vec::<Option<QuantityError>>::new());
+    let (unbound, set_unbound) = signal(false);
+
+    let select_parametrisation = move |index: usize| {
+        let fields = PARAMETRISATIONS[index].1;
+        set_texts.set(current_values(planet_signal.0.get_untracked(), index).iter().zip(fields.iter()).map(|(value, (_, kind, _))| {
+            format_quantity(*value, *kind, units.get_untracked(), language.get_untracked())
+        }).collect());
+        set_errors.set(vec![None; fields.len()]);
+        set_unbound.set(false);
+        set_parametrisation.set(index);
+    };
+
+    let apply = move || {
+        let index = parametrisation.get_untracked();
+        let fields = PARAMETRISATIONS[index].1;
+        let parsed: Vec<Result<f64, QuantityError>> = texts.get_untracked().iter().zip(fields.iter()).map(|(text, (_, kind, limits))| {
+            parse_quantity(text, *kind, *limits, units.get_untracked(), language.get_untracked())
+        }).collect();
+        set_errors.set(parsed.iter().map(|value| value.clone().err()).collect());
+
+        let Ok(values) = parsed.into_iter().collect::<Result<Vec<f64>, QuantityError>>() else {
+            return;
+        };
+        let planet = planet_signal.0.get_untracked();
+        match convert(planet, index, &values) {
+            Some((a, e)) => {
+                set_unbound.set(false);
+                update_planet_data(planet, planet_signal.1, a / AU, 0);
+                update_planet_data(planet, planet_signal.1, e, 1);
+            },
+            None => set_unbound.set(true),
+        }
+    };
+
+    let a = move || planet_signal.0.get().a.0.get();
+    let e = move || planet_signal.0.get().e.0.get();
+    let speed_at = move |angle: f64| {
+        let planet = planet_signal.0.get();
+        units.get().format_speed(calculate_orbital_velocity(a(), e(), planet.m_sun, planet.g, angle), language.get())
+    };
+
+    view! {
+        <div class="input_section">
+            <div class="input_select input">
+                <span class="input_select_label">{move || translate(language.get(), "Orbit defined by: ")}</span>
+                <select disabled=move || !editable.get() on:change=move |ev| select_parametrisation(event_target_value(&ev).parse::<usize>().unwrap_or(0))>
+                    {PARAMETRISATIONS.iter().enumerate().map(|(index, (name, _))| {
+                        let name = *name;
+                        view! {
+                            <option value={index.to_string()} selected=move || parametrisation.get() == index>{move || translate(language.get(), name)}</option>
+                        }
+                    }).collect_view()}
+                </select>
+            </div>
+        </div>
+        <Show when=move || editable.get() && parametrisation.get() != 0>
+            <div class="input_section">
+                <form class="input orbit_parameters" on:submit=move |ev| {
+                    ev.prevent_default();
+                    apply();
+                }>
+                    {move || PARAMETRISATIONS[parametrisation.get()].1.iter().enumerate().map(|(index, (label, _, _))| {
+                        let label = *label;
+                        view! {
+                            <label>{move || translate(language.get(), label)}
+                                <input class="input_number" type="text" inputmode="decimal" spellcheck="false"
+                                    aria-invalid=move || errors.get().get(index).is_some_and(|error| error.is_some()).to_string()
+                                    prop:value=move || texts.get().get(index).cloned().unwrap_or_default()
+                                    on:input=move |ev| set_texts.update(|texts| texts[index] = event_target_value(&ev)) />
+                            </label>
+                            {move || errors.get().get(index).cloned().flatten().map(|error| view! {<span class="input_error" role="alert">{error.message(language.get())}</span>})}
+                        }
+                    }).collect_view()}
+                    <button type="submit">{move || translate(language.get(), "Apply")}</button>
+                    <Show when=move || unbound.get()>
+                        <span class="input_error" role="alert">{move || translate(language.get(), "These values describe no closed orbit around the star")}</span>
+                    </Show>
+                </form>
+            </div>
+        </Show>
+        <div class="input_section">
+            <div class="input orbit_equivalents" aria-live="polite">
+                <span>"a = " {move || units.get().format_distance(a(), language.get())} ", e = " {move || format_number(language.get(), e(), 4)}</span>
+                <span>"q = " {move || units.get().format_distance(calculate_periapsis(a(), e()), language.get())} ", Q = " {move || units.get().format_distance(calculate_apoapsis(a(), e()), language.get())}</span>
+                <span>"T = " {move || {
+                    let planet = planet_signal.0.get();
+                    units.get().format_time(calculate_orbital_period(a(), planet.g, planet.m_object.0.get(), planet.m_sun, planet.m_earth), language.get())
+                }}</span>
+                <span>{move || translate(language.get(), "Speed at periapsis: ")} {move || speed_at(0.0)} ", " {move || translate(language.get(), "at apoapsis: ")} {move || speed_at(PI)}</span>
+            </div>
+        </div>
+    }
+}
\ No newline at end of file
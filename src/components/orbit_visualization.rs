@@ -1,9 +1,9 @@
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
-use super::planet::PlanetData;
-use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, MouseEvent};
+use super::{planet::PlanetData, orbit_visualization_options::VisualizationOptions, flyby::get_flyby, unit_settings::use_unit_settings, language::use_language, switch_view::is_view_active};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, MouseEvent, PointerEvent};
 use wasm_bindgen::{JsCast, closure::Closure};
-use crate::utils::{gravitational_force_sun::calculate_gravitational_force_with_sun, orbital_velocity::calculate_orbital_velocity, orbits::load_orbits, radius::calculate_radius};
+use crate::utils::{gravitational_force_sun::calculate_gravitational_force_with_sun, lagrange_points::{calculate_lagrange_points, calculate_mass_ratio}, orbital_velocity::calculate_orbital_velocity, orbits::load_orbits, radius::calculate_radius, i18n::{fill_template, translate}, quantity::format_value, state_vector::{state_vector_from_elements, Frame, KeplerianElements}};
 use std::{f64::consts::PI, rc::Rc, cell::RefCell};
 use serde_wasm_bindgen::to_value;
 
@@ -28,12 +28,20 @@ fn get_mouse_position(event: &MouseEvent, canvas: &HtmlCanvasElement) -> (i32, i
     (x, y, canvas_x, canvas_y)
 }
 
-fn normalize_radius_points(radius_points_vec: &mut Vec<(i8, Vec<RadiusPoint>)>, radius_points: &mut [RadiusPoint], height: f64, width: f64) -> f64 {
+// Events that move the probe, a touch only moves it while the finger is down
+const PROBE_EVENTS: [&str; 2] = ["pointermove", "pointerdown"];
+const MIN_ZOOM: f64 = 0.5;
+const MAX_ZOOM: f64 = 20.0;
 
+fn normalize_radius_points(radius_points_vec: &mut Vec<(i8, Vec<RadiusPoint>)>, radius_points: &mut [RadiusPoint], comparison_points: &mut [RadiusPoint], height: f64, width: f64, zoom: f64) -> f64 {
+
+    // The compared orbit has to fit on the canvas as well
     let max_radius = radius_points
         .iter()
+        .chain(comparison_points.iter())
         .map(|p| p.radius)
-        .max_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        .max_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|max_radius| max_radius / zoom);
 
     if let Some(max_radius) = max_radius {
         for section in radius_points_vec {
@@ -41,7 +49,7 @@ fn normalize_radius_points(radius_points_vec: &mut Vec<(i8, Vec<RadiusPoint>)>,
                 point.radius = (point.radius / max_radius) * (height.min(width) - 16.0) / 2.0;
             }
         }
-        for point in radius_points {
+        for point in radius_points.iter_mut().chain(comparison_points.iter_mut()) {
             point.radius = (point.radius / max_radius) * (height.min(width) - 16.0) / 2.0;
         }
 
@@ -63,25 +71,51 @@ pub fn get_radius_points(a: f64, e: f64, start: f64, end: f64, step: f64) -> Vec
     data
 }
 
+// (is_hovering, angle, radius, velocity, force) of the orbit at the true anomaly
+fn get_probe_properties(planet: &PlanetData, angle: f64) -> (bool, f64, f64, f64, f64) {
+    let radius = calculate_radius(planet.a.0.get_untracked(), planet.e.0.get_untracked(), angle);
+    let velocity = calculate_orbital_velocity(planet.a.0.get_untracked(), planet.e.0.get_untracked(), planet.m_sun, planet.g, angle);
+    let gravitational_force = calculate_gravitational_force_with_sun(
+        planet.m_object.0.get_untracked(),
+        planet.m_sun,
+        planet.m_earth,
+        planet.a.0.get_untracked(),
+        planet.e.0.get_untracked(),
+        planet.g,
+        angle,
+    );
+    (true, angle, radius, velocity, gravitational_force)
+}
+
+// Move the hover box to a point of the page
+fn place_info(x: f64, y: f64) {
+    if let Some(info_div) = document().get_element_by_id("orbit_visualization_info") {
+        info_div
+            .set_attribute("style", &format!("left: {}px; top: {}px;", x, y))
+            .unwrap();
+    }
+}
+
 fn mouse_hover(
-    ctx: Rc<CanvasRenderingContext2d>,
     width: f64,
     height: f64,
-    max_radius: f64,
     canvas: Rc<HtmlCanvasElement>,
     planet: Rc<RefCell<PlanetData>>,
+    options: VisualizationOptions,
     set_mouse_properties: WriteSignal<(bool, f64, f64, f64, f64)>,
     event_closure: Rc<RefCell<Option<Closure<dyn FnMut(MouseEvent)>>>>,
 ) {
     // If a closure already exists, remove it
     if let Some(existing_closure) = &*event_closure.borrow() {
         let window = web_sys::window().unwrap();
-        window
-            .remove_event_listener_with_callback("mousemove", existing_closure.as_ref().unchecked_ref())
-            .unwrap();
+        for event in PROBE_EVENTS {
+            window
+                .remove_event_listener_with_callback(event, existing_closure.as_ref().unchecked_ref())
+                .unwrap();
+        }
     }
 
-    // Create a new mousemove event
+    // Create a new pointer event for mice, pens and touches
     let hover_closure = Closure::wrap(Box::new(move |event: MouseEvent| {
         let (x, y, canvas_x, canvas_y) = get_mouse_position(&event, &canvas);
 
@@ -91,36 +125,12 @@ fn mouse_hover(
             let canvas_x = canvas_x - width / 2.0;
             let canvas_y = canvas_y - height / 2.0;
             let angle = canvas_y.atan2(canvas_x);
-            let radius = calculate_radius(planet.a.0.get_untracked(), planet.e.0.get_untracked(), angle);
-            let velocity = calculate_orbital_velocity(planet.a.0.get_untracked(), planet.e.0.get_untracked(), planet.m_sun, planet.g, angle);
-            let gravitational_force = calculate_gravitational_force_with_sun(
-                planet.m_object.0.get_untracked(),
-                planet.m_sun,
-                planet.m_earth,
-                planet.a.0.get_untracked(),
-                planet.e.0.get_untracked(),
-                planet.g,
-                angle,
-            );
-
-            set_mouse_properties((true, angle, radius, velocity, gravitational_force));
-
-            draw_scene(*planet, signal((false, 0.0, 0.0, 0.0, 0.0)).1, false, Rc::new(RefCell::new(None)));
-
-            let radius_normalized = (radius / max_radius) * ((height.min(width)) - 16.0) / 2.0;
-            ctx.set_stroke_style_str("red");
-            ctx.begin_path();
-            ctx.move_to(width / 2.0, height / 2.0);
-            ctx.line_to(
-                radius_normalized * angle.cos() + width / 2.0,
-                radius_normalized * angle.sin() + height / 2.0,
-            );
-            ctx.stroke();
-
-            let info_div = document().get_element_by_id("orbit_visualization_info").unwrap();
-            info_div
-                .set_attribute("style", &format!("left: {}px; top: {}px;", x, y))
-                .unwrap();
+
+            set_mouse_properties.set(get_probe_properties(&planet, angle));
+
+            draw_scene(*planet, options, signal((false, 0.0, 0.0, 0.0, 0.0)).1, false, Rc::new(RefCell::new(None)), Some(angle));
+
+            place_info(x as f64, y as f64);
         } else {
             set_mouse_properties((false, 0.0, 0.0, 0.0, 0.0));
         }
@@ -128,16 +138,19 @@ fn mouse_hover(
 
     // Add the new event listener
     let window = web_sys::window().unwrap();
-    window
-        .add_event_listener_with_callback("mousemove", hover_closure.as_ref().unchecked_ref())
-        .unwrap();
+    for event in PROBE_EVENTS {
+        window
+            .add_event_listener_with_callback(event, hover_closure.as_ref().unchecked_ref())
+            .unwrap();
+    }
 
     // Store the closure for future use
     *event_closure.borrow_mut() = Some(hover_closure);
 }
 
 
-fn draw_grid_lines(ctx: &CanvasRenderingContext2d, width: f64, height: f64, planet: PlanetData, max_radius: f64) {
+fn draw_grid_lines(ctx: &CanvasRenderingContext2d, width: f64, height: f64, planet: PlanetData, max_radius: f64, options: VisualizationOptions) {
+    let (units, language, color) = (options.units.get_untracked(), options.language.get_untracked(), options.theme.get_untracked().palette().grid);
 
     //Calculate special points
     let periapsis = calculate_radius((planet.a).0.get_untracked(), (planet.e).0.get_untracked(), 0.0);
@@ -149,8 +162,8 @@ fn draw_grid_lines(ctx: &CanvasRenderingContext2d, width: f64, height: f64, plan
 
     
     // Draw the grid-lines and labels
-    ctx.set_stroke_style_str("white");
-    ctx.set_fill_style_str("white");
+    ctx.set_stroke_style_str(color);
+    ctx.set_fill_style_str(color);
     ctx.set_line_dash(&to_value(&[5, 3]).unwrap()).expect("Failed to set line dash");
     ctx.begin_path();
     ctx.move_to(width / 2.0, height / 2.0);
@@ -164,14 +177,14 @@ fn draw_grid_lines(ctx: &CanvasRenderingContext2d, width: f64, height: f64, plan
     ctx.stroke();
     ctx.set_font("20px Arial");
     ctx.set_text_align("center");
-    ctx.fill_text(format!("{} AU", (apoapsis / (1.496 * (10.0_f64).powi(11)) * 100.0).round() / 100.0).as_str(), (width / 2.0) - (apoapsis_normalized / 2.0), height / 2. - 5.0).expect("Failed to write text");
-    ctx.fill_text(format!("{} AU", (periapsis / (1.496 * (10.0_f64).powi(11)) * 100.0).round() / 100.0).as_str(), (width / 2.0) + (periapsis_normalized / 2.0), height / 2. - 5.0).expect("Failed to write text");
+    ctx.fill_text(units.format_distance(apoapsis, language).as_str(), (width / 2.0) - (apoapsis_normalized / 2.0), height / 2. - 5.0).expect("Failed to write text");
+    ctx.fill_text(units.format_distance(periapsis, language).as_str(), (width / 2.0) + (periapsis_normalized / 2.0), height / 2. - 5.0).expect("Failed to write text");
     ctx.set_line_dash(&to_value::<Vec<u32>>(&vec![]).unwrap()).expect("Failed to set line dash");
 
 }
 
-fn draw_stored_orbits(ctx: &CanvasRenderingContext2d, radius_points_vec: Vec<(i8, Vec<RadiusPoint>)>, width: f64, height: f64) {
-    ctx.set_stroke_style_str("lime");
+fn draw_stored_orbits(ctx: &CanvasRenderingContext2d, radius_points_vec: Vec<(i8, Vec<RadiusPoint>)>, width: f64, height: f64, color: &str) {
+    ctx.set_stroke_style_str(color);
     for i in 0..radius_points_vec.len() {
         ctx.begin_path();
         for RadiusPoint { angle, radius } in &radius_points_vec[i].1 {
@@ -183,9 +196,9 @@ fn draw_stored_orbits(ctx: &CanvasRenderingContext2d, radius_points_vec: Vec<(i8
     }
 }
 
-fn draw_orbit(ctx: &CanvasRenderingContext2d, radius_points: &[RadiusPoint], width: f64, height: f64) {
+fn draw_orbit(ctx: &CanvasRenderingContext2d, radius_points: &[RadiusPoint], width: f64, height: f64, color: &str) {
     // Draw the orbit
-    ctx.set_stroke_style_str("white");
+    ctx.set_stroke_style_str(color);
     ctx.begin_path();
     for RadiusPoint { angle, radius } in radius_points {
         let x = radius * angle.cos();
@@ -195,7 +208,109 @@ fn draw_orbit(ctx: &CanvasRenderingContext2d, radius_points: &[RadiusPoint], wid
     ctx.stroke();
 }
 
-pub fn draw_scene(planet: PlanetData, set_mouse_properties: WriteSignal<(bool, f64, f64, f64, f64)>, with_hover: bool, event_closure: Rc<RefCell<Option<Closure<dyn FnMut(MouseEvent)>>>>,) {
+// Draw the orbit again for every revolution, rotated by the precession of the periapsis
+fn draw_rosette(ctx: &CanvasRenderingContext2d, radius_points: &[RadiusPoint], width: f64, height: f64, precession: f64, revolutions: u32, color: &str) {
+    ctx.set_stroke_style_str(color);
+    ctx.set_global_alpha(0.4);
+    for revolution in 1..=revolutions {
+        let rotation = precession * revolution as f64;
+        ctx.begin_path();
+        for RadiusPoint { angle, radius } in radius_points {
+            let x = radius * (angle + rotation).cos();
+            let y = radius * (angle + rotation).sin();
+            ctx.line_to(x + width / 2.0, y + height / 2.0);
+        }
+        ctx.stroke();
+    }
+    ctx.set_global_alpha(1.0);
+}
+
+// Draw the object at its periapsis and the five Lagrange points of that configuration
+fn draw_lagrange_points(ctx: &CanvasRenderingContext2d, width: f64, height: f64, planet: PlanetData, max_radius: f64, color: &str) {
+    let periapsis = calculate_radius(planet.a.0.get_untracked(), planet.e.0.get_untracked(), 0.0);
+    let periapsis_normalized = (periapsis / max_radius) * (height.min(width) - 16.0) / 2.0;
+    let mu = calculate_mass_ratio(planet.m_object.0.get_untracked() * planet.m_earth, planet.m_sun);
+
+    ctx.set_fill_style_str(color);
+    ctx.set_font("14px Arial");
+    ctx.set_text_align("center");
+    ctx.begin_path();
+    ctx.arc(width / 2.0 + periapsis_normalized, height / 2.0, 4.0, 0.0, 2.0 * PI).expect("Failed to draw arc");
+    ctx.fill();
+    for (index, (x, y)) in calculate_lagrange_points(mu).iter().enumerate() {
+        // The star is at -mu in the barycentric frame, the canvas is centered on the star and angles grow downwards
+        let canvas_x = width / 2.0 + (x + mu) * periapsis_normalized;
+        let canvas_y = height / 2.0 + y * periapsis_normalized;
+        ctx.begin_path();
+        ctx.arc(canvas_x, canvas_y, 3.0, 0.0, 2.0 * PI).expect("Failed to draw arc");
+        ctx.fill();
+        ctx.fill_text(&format!("L{}", index + 1), canvas_x, canvas_y - 8.0).expect("Failed to write text");
+    }
+}
+
+// Draw a conic section around the star, given as (semi-latus rectum, eccentricity, angle of the periapsis)
+fn draw_conic(ctx: &CanvasRenderingContext2d, (semi_latus_rectum, e, periapsis_angle): (f64, f64, f64), width: f64, height: f64, max_radius: f64) {
+    let scale = (height.min(width) - 16.0) / 2.0 / max_radius;
+    // Hyperbolic orbits only exist between their asymptotes
+    let limit = if e >= 1.0 { f64::acos(-1.0 / e) - 0.01 } else { PI };
+
+    ctx.begin_path();
+    let mut drawing = false;
+    let mut angle = -limit;
+    while angle <= limit {
+        let radius = semi_latus_rectum / (1.0 + e * angle.cos());
+        // Leave out the parts far outside of the canvas
+        if radius > 0.0 && radius < 10.0 * max_radius {
+            let x = radius * scale * (angle + periapsis_angle).cos() + width / 2.0;
+            let y = radius * scale * (angle + periapsis_angle).sin() + height / 2.0;
+            if drawing {
+                ctx.line_to(x, y);
+            } else {
+                ctx.move_to(x, y);
+            }
+            drawing = true;
+        } else {
+            drawing = false;
+        }
+        angle += 0.005;
+    }
+    ctx.stroke();
+}
+
+// Draw the orbits around the star before and after the flyby and the point of the encounter
+fn draw_flyby(ctx: &CanvasRenderingContext2d, width: f64, height: f64, planet: PlanetData, options: VisualizationOptions, max_radius: f64) {
+    let flyby = get_flyby(&planet, options.flyby);
+    let scale = (height.min(width) - 16.0) / 2.0 / max_radius;
+    let palette = options.theme.get_untracked().palette();
+
+    ctx.set_line_dash(&to_value(&[5, 3]).unwrap()).expect("Failed to set line dash");
+    ctx.set_stroke_style_str(palette.flyby_in);
+    draw_conic(ctx, flyby.orbit_in, width, height, max_radius);
+    ctx.set_line_dash(&to_value::<Vec<u32>>(&vec![]).unwrap()).expect("Failed to set line dash");
+    ctx.set_stroke_style_str(palette.flyby_out);
+    draw_conic(ctx, flyby.orbit_out, width, height, max_radius);
+
+    ctx.set_fill_style_str(palette.flyby_out);
+    ctx.begin_path();
+    ctx.arc(flyby.position.0 * scale + width / 2.0, flyby.position.1 * scale + height / 2.0, 4.0, 0.0, 2.0 * PI).expect("Failed to draw arc");
+    ctx.fill();
+}
+
+// Line from the star to the orbit at the true anomaly of the probe
+fn draw_probe(ctx: &CanvasRenderingContext2d, width: f64, height: f64, planet: PlanetData, max_radius: f64, angle: f64, color: &str) {
+    let radius = calculate_radius(planet.a.0.get_untracked(), planet.e.0.get_untracked(), angle);
+    let radius_normalized = (radius / max_radius) * ((height.min(width)) - 16.0) / 2.0;
+    ctx.set_stroke_style_str(color);
+    ctx.begin_path();
+    ctx.move_to(width / 2.0, height / 2.0);
+    ctx.line_to(
+        radius_normalized * angle.cos() + width / 2.0,
+        radius_normalized * angle.sin() + height / 2.0,
+    );
+    ctx.stroke();
+}
+
+pub fn draw_scene(planet: PlanetData, options: VisualizationOptions, set_mouse_properties: WriteSignal<(bool, f64, f64, f64, f64)>, with_hover: bool, event_closure: Rc<RefCell<Option<Closure<dyn FnMut(MouseEvent)>>>>, probe: Option<f64>) {
     let mut radius_points = get_radius_points(
         (planet.a).0.get_untracked(),
         (planet.e).0.get_untracked(),
@@ -224,50 +339,204 @@ pub fn draw_scene(planet: PlanetData, set_mouse_properties: WriteSignal<(bool, f
         .unwrap();
 
     ctx.clear_rect(0.0, 0.0, width, height);
+    let palette = options.theme.get_untracked().palette();
 
     let mut stored_radius_points = load_orbits();
 
-    let max_radius = normalize_radius_points(&mut stored_radius_points, &mut radius_points, width, height);
+    let mut comparison_radius_points = match options.comparison.0.get_untracked() {
+        Some(comparison) => get_radius_points(comparison.planet.a.0.get_untracked(), comparison.planet.e.0.get_untracked(), 0.0, 2.0 * PI, 0.01),
+        None => vec![],
+    };
+
+    let max_radius = normalize_radius_points(&mut stored_radius_points, &mut radius_points, &mut comparison_radius_points, width, height, options.zoom.0.get_untracked());
 
     // Draw stored orbits
-    draw_stored_orbits(&ctx, stored_radius_points, width, height);
+    draw_stored_orbits(&ctx, stored_radius_points, width, height, palette.stored_orbits);
+
+    // Draw the compared orbit below the current one
+    draw_orbit(&ctx, &comparison_radius_points, width, height, palette.comparison);
 
     // Draw the orbit
-    draw_orbit(&ctx, &radius_points, width, height);
+    draw_orbit(&ctx, &radius_points, width, height, palette.orbit);
+
+    // Draw the precessing orbits
+    let revolutions = options.rosette_progress.0.get_untracked().min(options.rosette_revolutions.0.get_untracked());
+    draw_rosette(&ctx, &radius_points, width, height, options.rosette_precession.0.get_untracked().to_radians(), revolutions, palette.grid);
 
     // Draw grid lines
-    draw_grid_lines(&ctx, width, height, planet, max_radius);
+    draw_grid_lines(&ctx, width, height, planet, max_radius, options);
+
+    if options.show_lagrange_points.0.get_untracked() && planet.m_object.0.get_untracked() != 0.0 {
+        draw_lagrange_points(&ctx, width, height, planet, max_radius, palette.lagrange_points);
+    }
+
+    if options.flyby.show.0.get_untracked() && planet.m_object.0.get_untracked() != 0.0 && planet.d.0.get_untracked() != 0.0 {
+        draw_flyby(&ctx, width, height, planet, options, max_radius);
+    }
+
+    if let Some(angle) = probe {
+        draw_probe(&ctx, width, height, planet, max_radius, angle, palette.probe);
+    }
 
     if with_hover {
-        mouse_hover(Rc::new(ctx), width, height, max_radius, Rc::new(canvas), Rc::new(RefCell::new(planet)), set_mouse_properties, event_closure.clone());
+        mouse_hover(width, height, Rc::new(canvas), Rc::new(RefCell::new(planet)), options, set_mouse_properties, event_closure.clone());
     }
 }
 
-pub fn create_scene(planet_signal: ReadSignal<PlanetData>, set_mouse_properties: WriteSignal<(bool, f64, f64, f64, f64)>) {
+pub fn create_scene(planet_signal: ReadSignal<PlanetData>, options: VisualizationOptions, set_mouse_properties: WriteSignal<(bool, f64, f64, f64, f64)>, visible: Memo<bool>) {
     let event_closure = Rc::new(RefCell::new(None));
+    // The canvas has no size while its view is hidden, redraw it once it is laid out again
+    let redraw_closure = event_closure.clone();
+    Effect::new(move |_| {
+        if visible.get() {
+            let event_closure = redraw_closure.clone();
+            request_animation_frame(move || draw_scene(planet_signal.get_untracked(), options, set_mouse_properties, true, event_closure, None));
+        } else {
+            set_mouse_properties((false, 0.0, 0.0, 0.0, 0.0));
+        }
+    });
     Effect::new(move |_| {
         let planet = planet_signal.get();
-
-        draw_scene(planet, set_mouse_properties, true,event_closure.clone());
+        // Redraw whenever the rosette changes
+        options.rosette_revolutions.0.track();
+        options.rosette_precession.0.track();
+        options.rosette_progress.0.track();
+        options.show_lagrange_points.0.track();
+        options.comparison.0.track();
+        options.units.track();
+        options.language.track();
+        options.theme.track();
+        options.zoom.0.track();
+        // Redraw whenever the flyby changes
+        options.flyby.show.0.track();
+        options.flyby.v_infinity.0.track();
+        options.flyby.altitude.0.track();
+        options.flyby.approach_angle.0.track();
+        options.flyby.trailing_side.0.track();
+        options.flyby.position.0.track();
+
+        draw_scene(planet, options, set_mouse_properties, true, event_closure.clone(), None);
     });
 }
 
+// Distance between the two fingers of a pinch
+fn pinch_distance(pointers: &[(i32, f64, f64)]) -> Option<f64> {
+    match pointers {
+        [(_, x1, y1), (_, x2, y2)] => Some((x2 - x1).hypot(y2 - y1)),
+        _ => None,
+    }
+}
+
 //Canvas visualization of the orbit with eccentricity and labels
 #[component]
-pub fn OrbitVisualization(planet: ReadSignal<PlanetData>, mouse_properties: ReadSignal<(bool, f64, f64, f64, f64)>, set_mouse_properties: WriteSignal<(bool, f64, f64, f64, f64)>) -> impl IntoView {    
-    create_scene(planet, set_mouse_properties);
+pub fn OrbitVisualization(planet: ReadSignal<PlanetData>, options: VisualizationOptions, mouse_properties: ReadSignal<(bool, f64, f64, f64, f64)>, set_mouse_properties: WriteSignal<(bool, f64, f64, f64, f64)>) -> impl IntoView {    
+    let active = is_view_active("orbit_visualization_canvas");
+    create_scene(planet, options, set_mouse_properties, active);
+    // (pointer id, x, y) of the pointers pressed on the canvas
+    let pointers = StoredValue::new(Vec::<(i32, f64, f64)>::new());
+    let release_pointer = move |ev: PointerEvent| pointers.update_value(|pointers| pointers.retain(|(id, _, _)| *id != ev.pointer_id()));
+    // True anomaly of the probe moved with the keyboard
+    let probe_angle = StoredValue::new(0.0_f64);
+    // Values of the probe read by screen readers, the pointer hovering over the canvas is not announced
+    let (announcement, set_announcement) = signal(String::new());
+    let units = use_unit_settings();
+    let language = use_language();
+    let hover_text = move || {
+        let (units, language, (_, angle, radius, velocity, force)) = (units.get(), language.get(), mouse_properties.get());
+        let values = vec![units.format_angle(angle, language), units.format_distance(radius, language), units.format_speed(velocity, language), units.format_force(force, language)];
+        let template = if force != 0.0 {
+            "At angle {} and radius {} the velocity is {} and the gravitational force with the star is {}"
+        } else {
+            "At angle {} and radius {} the velocity is {}"
+        };
+        fill_template(translate(language, template), &values)
+    };
+    // Position and velocity at the probe in a frame, the sky frame has the ascending node on the x axis
+    let state_text = move |frame: Frame| {
+        let (units, language, planet, (_, angle, _, _, _)) = (units.get(), language.get(), planet.get(), mouse_properties.get());
+        let (a, e) = (planet.a.0.get(), planet.e.0.get());
+        let elements = KeplerianElements {
+            p: a * (1.0 - e * e),
+            e,
+            inclination: planet.inclination.0.get().to_radians(),
+            longitude_ascending_node: 0.0,
+            argument_periapsis: planet.argument_periapsis.0.get().to_radians(),
+            true_anomaly: angle,
+        };
+        let state = state_vector_from_elements(&elements, planet.g * planet.m_sun, frame);
+        let position = state.position.map(|x| format_value(units.distance(x), language)).join("; ");
+        let velocity = state.velocity.map(|v| format_value(units.speed(v / 1000.0), language)).join("; ");
+        let frame_name = match frame {
+            Frame::Reference => "sky plane",
+            Frame::Perifocal => "perifocal",
+        };
+        fill_template(
+            translate(language, "State vector at this point ({}): r = ({}) {}, v = ({}) {}"),
+            &[String::from(translate(language, frame_name)), position, String::from(translate(language, units.distance_label())), velocity, String::from(translate(language, units.speed_label()))],
+        )
+    };
 
     view! { 
-        <canvas id="orbit_visualization_canvas" class="visible_element"/>
+        <canvas id="orbit_visualization_canvas" class="view" class:invisible_element=move || !active.get()
+            tabindex="0"
+            role="img"
+            aria-label=move || translate(language.get(), "Orbit visualization, the arrow keys move a probe along the orbit")
+            on:keydown=move |ev| {
+                // Shift takes bigger steps
+                let step = if ev.shift_key() { 10.0_f64 } else { 1.0 }.to_radians();
+                let angle = match ev.key().as_str() {
+                    "ArrowRight" | "ArrowUp" => probe_angle.get_value() + step,
+                    "ArrowLeft" | "ArrowDown" => probe_angle.get_value() - step,
+                    "Escape" => {
+                        set_mouse_properties((false, 0.0, 0.0, 0.0, 0.0));
+                        set_announcement.set(String::new());
+                        draw_scene(planet.get_untracked(), options, set_mouse_properties, false, Rc::new(RefCell::new(None)), None);
+                        return;
+                    },
+                    _ => return,
+                }.rem_euclid(2.0 * PI);
+                // The arrows move the probe instead of switching the view
+                ev.prevent_default();
+                ev.stop_propagation();
+                probe_angle.set_value(angle);
+                set_mouse_properties.set(get_probe_properties(&planet.get_untracked(), angle));
+                set_announcement.set(untrack(|| format!("{} {}", hover_text(), state_text(Frame::Reference))));
+                draw_scene(planet.get_untracked(), options, set_mouse_properties, false, Rc::new(RefCell::new(None)), Some(angle));
+                // The box is shown in the corner of the canvas once it exists
+                if let Some(canvas) = document().get_element_by_id("orbit_visualization_canvas") {
+                    let rect = canvas.get_bounding_client_rect();
+                    request_animation_frame(move || place_info(rect.left() + 16.0, rect.top() + 16.0));
+                }
+            }
+            on:pointerdown=move |ev| pointers.update_value(|pointers| pointers.push((ev.pointer_id(), ev.client_x() as f64, ev.client_y() as f64)))
+            on:pointermove=move |ev| {
+                let before = pointers.with_value(|pointers| pinch_distance(pointers));
+                pointers.update_value(|pointers| {
+                    if let Some(pointer) = pointers.iter_mut().find(|(id, _, _)| *id == ev.pointer_id()) {
+                        *pointer = (ev.pointer_id(), ev.client_x() as f64, ev.client_y() as f64);
+                    }
+                });
+                let after = pointers.with_value(|pointers| pinch_distance(pointers));
+                if let (Some(before), Some(after)) = (before, after) {
+                    if before > 0.0 {
+                        options.zoom.1.update(|zoom| *zoom = (*zoom * after / before).clamp(MIN_ZOOM, MAX_ZOOM));
+                    }
+                }
+            }
+            on:pointerup=release_pointer
+            on:pointercancel=release_pointer
+            on:dblclick=move |_| options.zoom.1.set(1.0)
+        />
         <Show when=move || { mouse_properties.get().0 }>
             <div id="orbit_visualization_info">
-                <Show when=move || {mouse_properties.get().4 != 0.0}
-                    fallback=move || {view!{
-                        <span>{format!("At angle: {:.2} Rads and radius {:.2} AU the velocity is {:.3} km/s", mouse_properties.get().1,  mouse_properties.get().2 / (1.496 * (10.0_f64).powi(11)), mouse_properties.get().3)}</span>
-                    }}>
-                    <span>{format!("At angle: {:.2} Rads and radius {:.2} AU the velocity is {:.3} km/s and the gravitational force with the Sun is {:.3e} N", mouse_properties.get().1,  mouse_properties.get().2 / (1.496 * (10.0_f64).powi(11)), mouse_properties.get().3, mouse_properties.get().4)}</span>
-                </Show>
+                <span>{hover_text}</span>
+                <br/>
+                <span>{move || state_text(Frame::Reference)}</span>
+                <br/>
+                <span>{move || state_text(Frame::Perifocal)}</span>
             </div>
         </Show>
+        // Screen readers announce the values of the probe
+        <div class="visually_hidden" role="status" aria-live="polite">{announcement}</div>
     }
 }
\ No newline at end of file
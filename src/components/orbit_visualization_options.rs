@@ -1,12 +1,139 @@
 use leptos::prelude::*;
+use std::time::Duration;
 
-use crate::{components::orbit_visualization::{get_radius_points, draw_scene}, utils::orbits::{add_orbit, remove_last_orbit, clear_orbits}};
-use super::planet::PlanetData;
+use crate::{components::orbit_visualization::{get_radius_points, draw_scene}, utils::{i18n::Language, units::UnitSettings, theme::Theme, orbits::{add_orbit, remove_last_orbit, clear_orbits}, precession::calculate_relativistic_precession, presets::{load_exoplanet_presets, load_presets}}};
+use super::{planet::{PlanetData, create_planet}, flyby::FlybyParameters, unit_settings::use_unit_settings, language::use_language, theme::use_theme};
+use super::switch_view::is_view_active;
+
+// Second body shown next to the current one
+#[derive(Clone, PartialEq)]
+pub struct ComparisonBody {
+    pub name: String,
+    pub planet: PlanetData,
+}
+
+#[derive(Clone, PartialEq, Copy)]
+pub struct VisualizationOptions {
+    // Number of revolutions of the precession rosette, 0 disables it
+    pub rosette_revolutions: (ReadSignal<u32>, WriteSignal<u32>),
+    // Rotation of the periapsis per revolution in degrees
+    pub rosette_precession: (ReadSignal<f64>, WriteSignal<f64>),
+    // Revolutions currently drawn by the time-lapse
+    pub rosette_progress: (ReadSignal<u32>, WriteSignal<u32>),
+    pub show_lagrange_points: (ReadSignal<bool>, WriteSignal<bool>),
+    pub flyby: FlybyParameters,
+    pub comparison: (ReadSignal<Option<ComparisonBody>>, WriteSignal<Option<ComparisonBody>>),
+    // Magnification of the orbit canvas, changed by pinching
+    pub zoom: (ReadSignal<f64>, WriteSignal<f64>),
+    // Units and language of the labels drawn on the canvas
+    pub units: ReadSignal<UnitSettings>,
+    pub language: ReadSignal<Language>,
+    // Theme giving the colours of the canvas
+    pub theme: ReadSignal<Theme>,
+}
+
+impl VisualizationOptions {
+    fn new() -> Self {
+        let (rosette_revolutions, set_rosette_revolutions) = signal(0);
+        let (rosette_precession, set_rosette_precession) = signal(10.0);
+        let (rosette_progress, set_rosette_progress) = signal(0);
+        let (show_lagrange_points, set_show_lagrange_points) = signal(false);
+        let (comparison, set_comparison) = signal(None);
+        let (zoom, set_zoom) = signal(1.0);
+        Self {
+            rosette_revolutions: (rosette_revolutions, set_rosette_revolutions),
+            rosette_precession: (rosette_precession, set_rosette_precession),
+            rosette_progress: (rosette_progress, set_rosette_progress),
+            show_lagrange_points: (show_lagrange_points, set_show_lagrange_points),
+            flyby: FlybyParameters::new(),
+            comparison: (comparison, set_comparison),
+            zoom: (zoom, set_zoom),
+            units: use_unit_settings(),
+            language: use_language(),
+            theme: use_theme(),
+        }
+    }
+}
+
+pub fn create_visualization_options() -> VisualizationOptions {
+    VisualizationOptions::new()
+}
+
+#[component]
+fn ComparisonSelect(planet: ReadSignal<PlanetData>, options: VisualizationOptions) -> impl IntoView {
+    let presets = move || load_presets().into_iter().chain(load_exoplanet_presets());
+
+    view! {
+        <label>"Compare with "
+            <select on:change=move |ev| {
+                let value = event_target_value(&ev);
+                let comparison = match value.as_str() {
+                    "none" => None,
+                    "pinned" => Some(ComparisonBody { name: String::from("Pinned object"), planet: planet.get_untracked().snapshot() }),
+                    index => index.parse::<usize>().ok().and_then(|index| presets().nth(index)).map(|preset| {
+                        ComparisonBody { name: preset.name.clone(), planet: create_planet(&preset) }
+                    }),
+                };
+                options.comparison.1.set(comparison);
+            }>
+                <option value="none">"Nothing"</option>
+                <option value="pinned">"Pin the current object"</option>
+                {presets().enumerate().map(|(index, preset)| view! {
+                    <option value={index.to_string()}>{preset.name}</option>
+                }).collect_view()}
+            </select>
+        </label>
+    }
+}
+
+#[component]
+fn RosetteOptions(planet: ReadSignal<PlanetData>, options: VisualizationOptions) -> impl IntoView {
+    let (timelapse, set_timelapse) = signal(None::<IntervalHandle>);
+
+    let relativistic_precession = move || calculate_relativistic_precession(planet.get().a.0.get(), planet.get().e.0.get(), planet.get().m_sun, planet.get().g).to_degrees();
+
+    view! {
+        <span>"Precessing orbit rosette"</span>
+        <label>"Revolutions: "
+            <input type="number" min="0" max="360" step="1" prop:value=move || options.rosette_revolutions.0.get() on:input=move |ev| {
+                let revolutions = event_target_value(&ev).parse::<u32>().unwrap_or(0);
+                options.rosette_revolutions.1.set(revolutions);
+                options.rosette_progress.1.set(revolutions);
+            } />
+        </label>
+        <label>"Precession per revolution (°): "
+            <input type="number" min="0" max="180" step="0.1" prop:value=move || options.rosette_precession.0.get() on:input=move |ev| {
+                options.rosette_precession.1.set(event_target_value(&ev).parse::<f64>().unwrap_or(0.0));
+            } />
+        </label>
+        <span class="note">{move || format!("Relativistic value: {:.3e}° per revolution", relativistic_precession())}</span>
+        <button on:click=move |_| {
+            if let Some(handle) = timelapse.get_untracked() {
+                handle.clear();
+            }
+            options.rosette_progress.1.set(0);
+
+            // Add one revolution at a time until the rosette is complete
+            let handle = set_interval_with_handle(move || {
+                let progress = options.rosette_progress.0.get_untracked();
+                if progress >= options.rosette_revolutions.0.get_untracked() {
+                    if let Some(handle) = timelapse.get_untracked() {
+                        handle.clear();
+                    }
+                } else {
+                    options.rosette_progress.1.set(progress + 1);
+                }
+            }, Duration::from_millis(250)).ok();
+            set_timelapse.set(handle);
+        }>"Play time-lapse"</button>
+    }
+}
 
 #[component]
-pub fn OrbitVisualizationOptions(planet: ReadSignal<PlanetData>) -> impl IntoView {
+pub fn OrbitVisualizationOptions(planet: ReadSignal<PlanetData>, options: VisualizationOptions) -> impl IntoView {
+    let active = is_view_active("orbit_visualization_options");
     view!{
-        <div id="orbit_visualization_options" class="invisible_element">
+        <div id="orbit_visualization_options" class="view" class:invisible_element=move || !active.get()>
             <span>"Add or remove orbits to the visualization"</span>
             <button on:click=move |_| {
                 let radius_points = get_radius_points(
@@ -18,18 +145,25 @@ pub fn OrbitVisualizationOptions(planet: ReadSignal<PlanetData>) -> impl IntoVie
                 );
 
                 add_orbit(&radius_points);
-                draw_scene(planet.get(), signal((false, 0.0, 0.0, 0.0, 0.0)).1, false, std::rc::Rc::new(std::cell::RefCell::new(None)));
+                draw_scene(planet.get(), options, signal((false, 0.0, 0.0, 0.0, 0.0)).1, false, std::rc::Rc::new(std::cell::RefCell::new(None)), None);
             }>"Add Orbit"</button>
             <button on:click=move |_| {
                 remove_last_orbit();
 
-                draw_scene(planet.get(), signal((false, 0.0, 0.0, 0.0, 0.0)).1, false, std::rc::Rc::new(std::cell::RefCell::new(None)));
+                draw_scene(planet.get(), options, signal((false, 0.0, 0.0, 0.0, 0.0)).1, false, std::rc::Rc::new(std::cell::RefCell::new(None)), None);
             }>"Remove last Orbit"</button>
             <button on:click=move |_| {
                 clear_orbits();
 
-                draw_scene(planet.get(), signal((false, 0.0, 0.0, 0.0, 0.0)).1, false, std::rc::Rc::new(std::cell::RefCell::new(None)));
+                draw_scene(planet.get(), options, signal((false, 0.0, 0.0, 0.0, 0.0)).1, false, std::rc::Rc::new(std::cell::RefCell::new(None)), None);
             }>"Clear Orbits"</button>
+            <label>"Show Lagrange points "
+                <input type="checkbox" prop:checked=move || options.show_lagrange_points.0.get() on:change=move |ev| {
+                    options.show_lagrange_points.1.set(event_target_checked(&ev));
+                } />
+            </label>
+            <ComparisonSelect planet={planet} options={options} />
+            <RosetteOptions planet={planet} options={options} />
         </div>
     }
-}
\ No newline at end of file
+}
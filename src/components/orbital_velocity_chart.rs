@@ -3,71 +3,183 @@ use leptos_chartistry::*;
 use leptos_chartistry::IntoInner;
 use std::f64::consts::PI;
 
-use crate::utils::orbital_velocity::calculate_orbital_velocity;
-use super::planet::PlanetData;
+use crate::utils::{orbital_velocity::{calculate_flight_path_angle, calculate_orbital_velocity, calculate_radial_velocity_component, calculate_tangential_velocity_component}, radius::calculate_radius, i18n::translate, units::UnitSettings};
+use super::{chart_axes::{count_hidden_points, scale_chart_data, AxisControls, AxisState, ChartPoint, ClippingIndicator}, planet::PlanetData, orbit_visualization_options::ComparisonBody, unit_settings::use_unit_settings, language::{translated_title, use_language}, chart_table::ChartTable};
+use super::switch_view::is_view_active;
+
+// Quantities on the Y axis
+const QUANTITIES: [&str; 3] = ["Speed", "Speed, radial and tangential velocity", "Flight path angle"];
+
+// Variables on the X axis
+const X_VARIABLES: [&str; 2] = ["True anomaly", "Distance from the star"];
 
 #[derive(Clone, PartialEq)]
 struct ChartData {
     x: f64,
-    y: f64
+    y: f64,
+    y_radial: f64,
+    y_tangential: f64,
+    y_comparison: f64
 }
 
 impl ChartData {
-    fn add(x: f64, y: f64) -> Self {
-        Self { x, y }
+    fn add(x: f64, y: f64, y_radial: f64, y_tangential: f64) -> Self {
+        Self { x, y, y_radial, y_tangential, y_comparison: f64::NAN }
     }
 }
 
-fn get_chart_data(a: f64, e: f64, m: f64, g: f64, start: f64, end: f64, step: f64) -> Vec<ChartData> {
+impl ChartPoint for ChartData {
+    fn values_mut(&mut self) -> (&mut f64, Vec<&mut f64>) {
+        (&mut self.x, vec![&mut self.y, &mut self.y_radial, &mut self.y_tangential, &mut self.y_comparison])
+    }
+}
+
+// Values over one orbit against the true anomaly or the distance, which shows the vis-viva relation directly
+fn get_chart_data(planet: &PlanetData, quantity: usize, x_variable: usize, units: UnitSettings, step: f64) -> Vec<ChartData> {
+    let (a, e, m, g) = (planet.a.0.get(), planet.e.0.get(), planet.m_sun, planet.g);
     let mut data = Vec::new();
-    let mut angle = start;
-    while angle < end {
-        let velocity = calculate_orbital_velocity(a, e, m, g, angle);
-        if velocity <= 40.0 {
-            data.push(ChartData::add(angle, velocity));
-        }
+    let mut angle = 0.0;
+    while angle < 2.0 * PI {
+        let x = if x_variable == 0 { units.angle(angle) } else { units.distance(calculate_radius(a, e, angle)) };
+        data.push(if quantity == 2 {
+            ChartData::add(x, units.angle(calculate_flight_path_angle(e, angle).to_radians()), f64::NAN, f64::NAN)
+        } else {
+            ChartData::add(
+                x,
+                units.speed(calculate_orbital_velocity(a, e, m, g, angle)),
+                units.speed(calculate_radial_velocity_component(a, e, m, g, angle)),
+                units.speed(calculate_tangential_velocity_component(a, e, m, g, angle)),
+            )
+        });
         angle += step;
     }
     data
 }
 
 #[component]
-pub fn VelocityChart(planet: ReadSignal<PlanetData>) -> impl IntoView {
+pub fn VelocityChart(planet: ReadSignal<PlanetData>, comparison: ReadSignal<Option<ComparisonBody>>) -> impl IntoView {
+    let (quantity, set_quantity) = signal(0_usize);
+    let (x_variable, set_x_variable) = signal(0_usize);
+    let units = use_unit_settings();
+    let language = use_language();
+
     let chart_data = Memo::new(move |_| {
-        get_chart_data(
-            (planet.get().a).0.get(),
-            (planet.get().e).0.get(),
-            planet.get().m_sun,
-            planet.get().g,
-            0.0,
-            2.0 * PI,
-            0.01,
-        )
+        let (quantity, x_variable, units) = (quantity.get(), x_variable.get(), units.get());
+        let mut data = get_chart_data(&planet.get(), quantity, x_variable, units, 0.01);
+        // The compared orbit covers other distances, so its points follow as separate rows
+        if let Some(comparison) = comparison.get() {
+            let comparison_data = get_chart_data(&comparison.planet, quantity, x_variable, units, 0.01);
+            data.extend(comparison_data.into_iter().map(|point| ChartData { y_comparison: point.y, ..ChartData::add(point.x, f64::NAN, f64::NAN, f64::NAN) }));
+        }
+        data
     });
+    let comparison_name = Memo::new(move |_| comparison.get().map(|comparison| comparison.name));
 
-    let series = Series::new(|data: &ChartData| data.x)
-        .line(Line::new(|data: &ChartData| data.y).with_name("Velocity (km/s):"))
-        .with_y_range(0.0, 40.0)
-        .with_x_range(0.0, 6.3);
+    let (x_axis, set_x_axis) = signal(AxisState::default());
+    let (y_axis, set_y_axis) = signal(AxisState::default());
+    let scaled_data = Memo::new(move |_| scale_chart_data(&chart_data.get(), x_axis.get(), y_axis.get()));
+    let hidden_points = Memo::new(move |_| count_hidden_points(&chart_data.get(), x_axis.get(), y_axis.get()));
 
+    // The table lists the values before the axes are scaled
+    let table_columns = Signal::derive(move || {
+        let (quantity, x_variable, units, language) = (quantity.get(), x_variable.get(), units.get(), language.get());
+        let unit = translate(language, if quantity == 2 { units.angle_label() } else { units.speed_label() });
+        let x_unit = translate(language, if x_variable == 0 { units.angle_label() } else { units.distance_label() });
+        let mut columns = vec![
+            format!("{} ({})", translate(language, X_VARIABLES[x_variable]), x_unit),
+            format!("{} ({})", translate(language, if quantity == 2 { "Flight path angle" } else { "Speed" }), unit),
+        ];
+        if quantity == 1 {
+            columns.push(format!("{} ({})", translate(language, "Radial"), unit));
+            columns.push(format!("{} ({})", translate(language, "Tangential"), unit));
+        }
+        if let Some(name) = comparison_name.get() {
+            columns.push(format!("{} ({})", name, unit));
+        }
+        columns
+    });
+    let table_rows = Signal::derive(move || {
+        let (quantity, with_comparison) = (quantity.get(), comparison_name.get().is_some());
+        chart_data.get().iter().map(|data| {
+            let mut row = vec![data.x, data.y];
+            if quantity == 1 {
+                row.extend([data.y_radial, data.y_tangential]);
+            }
+            if with_comparison {
+                row.push(data.y_comparison);
+            }
+            row
+        }).collect()
+    });
+
+    let active = is_view_active("velocity_chart");
     view! {
-        <div id="velocity_chart" class="invisible_element">
-            <Chart
-                aspect_ratio=AspectRatio::from_env()
-                series=series
-                data=chart_data  
-                top=RotatedLabel::middle("Orbital Velocity")
-                left=vec![RotatedLabel::end("Velocity (km/s)").into(), TickLabels::aligned_floats().into()]
-                bottom=vec![TickLabels::aligned_floats().into(), RotatedLabel::end("Radius (Rad)").into(),]
-                inner=[
-                    AxisMarker::left_edge().into_inner(),
-                    AxisMarker::bottom_edge().into_inner(),
-                    XGridLine::default().into_inner(),
-                    YGridLine::default().into_inner(),
-                    YGuideLine::over_mouse().into_inner(),
-                    XGuideLine::over_data().into_inner(),
-                ]
-            />
+        <div id="velocity_chart" class="view" class:invisible_element=move || !active.get()>
+            <div class="chart_options">
+                <select on:change=move |ev| set_quantity.set(event_target_value(&ev).parse::<usize>().unwrap_or(0))>
+                    {QUANTITIES.iter().enumerate().map(|(index, &name)| view! {
+                        <option value={index.to_string()}>{move || translate(language.get(), name)}</option>
+                    }).collect_view()}
+                </select>
+                <label>"Against "
+                    <select on:change=move |ev| set_x_variable.set(event_target_value(&ev).parse::<usize>().unwrap_or(0))>
+                        {X_VARIABLES.iter().enumerate().map(|(index, &name)| view! {
+                            <option value={index.to_string()}>{move || translate(language.get(), name)}</option>
+                        }).collect_view()}
+                    </select>
+                </label>
+            </div>
+            <div class="chart_options">
+                <AxisControls label="X axis" axis=(x_axis, set_x_axis) />
+                <AxisControls label="Y axis" axis=(y_axis, set_y_axis) />
+                <ClippingIndicator hidden_points=hidden_points />
+            </div>
+            // Rebuild the chart when the quantities, a comparison or the axes change
+            {move || {
+                let (x_axis, y_axis, language) = (x_axis.get(), y_axis.get(), language.get());
+                let ((min_x, max_x), (min_y, max_y)) = (x_axis.range(), y_axis.range());
+                let (quantity, x_variable, units) = (quantity.get(), x_variable.get(), units.get());
+                let unit = translate(language, if quantity == 2 { units.angle_label() } else { units.speed_label() });
+                let x_unit = translate(language, if x_variable == 0 { units.angle_label() } else { units.distance_label() });
+                let mut series = Series::new(|data: &ChartData| data.x)
+                    .line(Line::new(|data: &ChartData| data.y).with_name(format!("{} ({}):", translate(language, if quantity == 2 { "Flight path angle" } else { "Speed" }), unit)))
+                    .with_min_x(min_x)
+                    .with_max_x(max_x)
+                    .with_min_y(min_y)
+                    .with_max_y(max_y);
+                if quantity == 1 {
+                    series = series
+                        .line(Line::new(|data: &ChartData| data.y_radial).with_name(format!("{} ({}):", translate(language, "Radial"), unit)))
+                        .line(Line::new(|data: &ChartData| data.y_tangential).with_name(format!("{} ({}):", translate(language, "Tangential"), unit)));
+                }
+                let mut top = vec![translated_title("Orbital Velocity").into()];
+                if let Some(name) = comparison_name.get() {
+                    series = series.line(Line::new(|data: &ChartData| data.y_comparison).with_name(format!("{} ({}):", name, unit)));
+                }
+                if quantity == 1 || comparison_name.get().is_some() {
+                    top.push(Legend::middle().into());
+                }
+
+                view! {
+                    <Chart
+                        aspect_ratio=AspectRatio::from_env()
+                        series=series
+                        data=scaled_data
+                        top=top
+                        left=vec![RotatedLabel::end(format!("{} ({})", translate(language, if quantity == 2 { "Flight path angle" } else { "Velocity" }), unit)).into(), TickLabels::aligned_floats().with_format(move |value, _| y_axis.format_tick(*value, language)).into()]
+                        bottom=vec![TickLabels::aligned_floats().with_format(move |value, _| x_axis.format_tick(*value, language)).into(), RotatedLabel::end(format!("{} ({})", translate(language, X_VARIABLES[x_variable]), x_unit)).into(),]
+                        inner=[
+                            AxisMarker::left_edge().into_inner(),
+                            AxisMarker::bottom_edge().into_inner(),
+                            XGridLine::default().into_inner(),
+                            YGridLine::default().into_inner(),
+                            YGuideLine::over_mouse().into_inner(),
+                            XGuideLine::over_data().into_inner(),
+                        ]
+                    />
+                }
+            }}
+            <ChartTable columns=table_columns rows=table_rows />
         </div>
     }
 }
\ No newline at end of file
@@ -1,8 +1,8 @@
 use leptos::prelude::*;
 use leptos::ev::Event;
-use wasm_bindgen::JsCast;
 
-use crate::utils::presets::*;
+use crate::utils::{exoplanet_archive::parse_exoplanet_archive_csv, imported_presets::{add_imported_presets, clear_imported_presets, load_imported_presets}, presets::*, i18n::{format_number, translate}, quantity::QuantityKind, units::{AU, EARTH_DIAMETER, EARTH_MASS, GRAVITATIONAL_CONSTANT, SOLAR_LUMINOSITY, SOLAR_MASS, SOLAR_RADIUS}};
+use super::{language::{use_language, LanguageSelect}, orbit_parameters::OrbitParameters, quantity_input::QuantityInput, theme::ThemeSelect, unit_settings::{use_unit_settings, UnitSettingsSelect}};
 
 #[derive(Clone, PartialEq, Copy)]
 pub struct PlanetData {
@@ -14,129 +14,301 @@ pub struct PlanetData {
     pub m_earth: f64,
     pub d: (ReadSignal<f64>, WriteSignal<f64>),
     pub d_earth: f64,
-    pub g: f64
+    pub g: f64,
+    pub rotation_period: (ReadSignal<f64>, WriteSignal<f64>),
+    pub axial_tilt: (ReadSignal<f64>, WriteSignal<f64>),
+    pub inclination: (ReadSignal<f64>, WriteSignal<f64>),
+    pub argument_periapsis: (ReadSignal<f64>, WriteSignal<f64>),
+    pub j2: (ReadSignal<f64>, WriteSignal<f64>),
+    // Host star radius (m), luminosity (W) and effective temperature (K)
+    pub r_sun: f64,
+    pub l_sun: f64,
+    pub t_sun: f64
 }
 
 impl PlanetData {
-    fn new(a_new: f64, e_new: f64, m_object_new: f64, d_new: f64) -> Self {
-        let (a, set_a) = signal(a_new * 1.496 * (10.0_f64).powi(11));
-        let (e, set_e) = signal(e_new);
-        let (a_input, set_a_input) = signal(a_new);
-        let (m_object, set_m_object) = signal(m_object_new);
-        let (d, set_d) = signal(d_new);
+    fn new(preset: &PlanetPreset) -> Self {
+        let (a, set_a) = signal(preset.a * AU);
+        let (e, set_e) = signal(preset.e);
+        let (a_input, set_a_input) = signal(preset.a);
+        let (m_object, set_m_object) = signal(preset.m);
+        let (d, set_d) = signal(preset.d);
+        let (rotation_period, set_rotation_period) = signal(preset.rotation_period);
+        let (axial_tilt, set_axial_tilt) = signal(preset.axial_tilt);
+        let (inclination, set_inclination) = signal(preset.inclination);
+        let (argument_periapsis, set_argument_periapsis) = signal(preset.argument_periapsis);
+        let (j2, set_j2) = signal(preset.j2);
         Self {
             a_input: (a_input, set_a_input),
             a: (a, set_a),
             e: (e, set_e),
-            m_sun: 1.988416 * (10.0_f64).powi(30),
+            m_sun: preset.star.m * SOLAR_MASS,
             m_object: (m_object, set_m_object),
-            m_earth: 5.972168 * 10.0_f64.powi(24),
+            m_earth: EARTH_MASS,
             d: (d, set_d),
-            d_earth: 12742.46,
-            g: 6.67430 * (10.0_f64).powi(-11)
+            d_earth: EARTH_DIAMETER,
+            g: GRAVITATIONAL_CONSTANT,
+            rotation_period: (rotation_period, set_rotation_period),
+            axial_tilt: (axial_tilt, set_axial_tilt),
+            inclination: (inclination, set_inclination),
+            argument_periapsis: (argument_periapsis, set_argument_periapsis),
+            j2: (j2, set_j2),
+            r_sun: preset.star.r * SOLAR_RADIUS,
+            l_sun: preset.star.l * SOLAR_LUMINOSITY,
+            t_sun: preset.star.t_eff
         }
     }
+
+    // Copy of the current values that no longer follows the inputs
+    pub fn snapshot(&self) -> Self {
+        Self::new(&PlanetPreset {
+            name: String::from("Pinned object"),
+            m: self.m_object.0.get_untracked(),
+            d: self.d.0.get_untracked(),
+            a: self.a.0.get_untracked() / AU,
+            e: self.e.0.get_untracked(),
+            rotation_period: self.rotation_period.0.get_untracked(),
+            axial_tilt: self.axial_tilt.0.get_untracked(),
+            j2: self.j2.0.get_untracked(),
+            inclination: self.inclination.0.get_untracked(),
+            argument_periapsis: self.argument_periapsis.0.get_untracked(),
+            star: StarPreset {
+                name: String::new(),
+                m: self.m_sun / SOLAR_MASS,
+                r: self.r_sun / SOLAR_RADIUS,
+                l: self.l_sun / SOLAR_LUMINOSITY,
+                t_eff: self.t_sun,
+            },
+            ..Default::default()
+        })
+    }
 }
 
-pub fn create_planet(a: f64, e: f64, m: f64, d: f64) -> PlanetData {
-    let planet = PlanetData::new(a, e, m, d);
+pub fn create_planet(preset: &PlanetPreset) -> PlanetData {
+    let planet = PlanetData::new(preset);
     planet
-} 
+}
+
+// Build a preset from the current values of the planet around the given host star
+fn custom_preset(planet: PlanetData, star: StarPreset) -> PlanetPreset {
+    PlanetPreset {
+        name: String::from("Custom"),
+        m: planet.m_object.0.get_untracked(),
+        d: planet.d.0.get_untracked(),
+        a: planet.a_input.0.get_untracked(),
+        e: planet.e.0.get_untracked(),
+        rotation_period: planet.rotation_period.0.get_untracked(),
+        axial_tilt: planet.axial_tilt.0.get_untracked(),
+        inclination: planet.inclination.0.get_untracked(),
+        argument_periapsis: planet.argument_periapsis.0.get_untracked(),
+        star,
+        ..Default::default()
+    }
+}
 
-pub fn update_planet_data(planet: PlanetData, update_planet: WriteSignal<PlanetData>, ev: Event, index: i8) {
-    let value = event_target_value(&ev).parse::<f64>().unwrap_or(0.0);
+fn import_presets(ev: Event, set_imported_presets: WriteSignal<Vec<PlanetPreset>>, set_import_error: WriteSignal<Option<String>>) {
+    let input = event_target::<web_sys::HtmlInputElement>(&ev);
+    let Some(file) = input.files().and_then(|files| files.get(0)) else {
+        return;
+    };
+
+    leptos::task::spawn_local(async move {
+        let text = wasm_bindgen_futures::JsFuture::from(file.text()).await.ok().and_then(|text| text.as_string()).unwrap_or_default();
+        match parse_exoplanet_archive_csv(&text) {
+            Ok(presets) => {
+                add_imported_presets(&presets);
+                set_imported_presets.set(load_imported_presets());
+                set_import_error.set(None);
+            },
+            Err(e) => set_import_error.set(Some(e)),
+        }
+    });
+}
+
+// Value of the input with the index, in the unit of the input
+pub fn read_planet_data(planet: PlanetData, index: i8) -> f64 {
+    match index {
+        0 => planet.a_input.0.get(),
+        1 => planet.e.0.get(),
+        2 => planet.m_object.0.get(),
+        3 => planet.d.0.get(),
+        4 => planet.rotation_period.0.get(),
+        5 => planet.axial_tilt.0.get(),
+        6 => planet.inclination.0.get(),
+        _ => planet.argument_periapsis.0.get(),
+    }
+}
+
+pub fn update_planet_data(planet: PlanetData, update_planet: WriteSignal<PlanetData>, value: f64, index: i8) {
     if index == 0 {
         (planet.a_input).1.set(value);
-        (planet.a).1.set(value * 1.496 * (10.0_f64).powi(11));
+        (planet.a).1.set(value * AU);
     } else if index == 1 {
         (planet.e).1.set(value);        
     } else if index == 2 {
         (planet.m_object).1.set(value);
     } else if index == 3 {
         (planet.d).1.set(value);
+    } else if index == 4 {
+        (planet.rotation_period).1.set(value);
+    } else if index == 5 {
+        (planet.axial_tilt).1.set(value);
+    } else if index == 6 {
+        (planet.inclination).1.set(value);
+    } else if index == 7 {
+        (planet.argument_periapsis).1.set(value);
     }
     update_planet.set(planet);
 }
 
 #[component]
 pub fn Inputs(planet_signal: (ReadSignal<PlanetData>, WriteSignal<PlanetData>)) -> impl IntoView {
-    let presets = load_presets();
+    let (imported_presets, set_imported_presets) = signal(load_imported_presets());
+    let (import_error, set_import_error) = signal(None::<String>);
+    let (preset_index, set_preset_index) = signal(0_usize);
+    let (host_star, set_host_star) = signal(0_usize);
+    let units = use_unit_settings();
+    let language = use_language();
+
+    // Solar system, exoplanet and imported presets share one list, the select value is the index + 1
+    let presets = move || {
+        let mut presets = load_presets();
+        presets.extend(load_exoplanet_presets());
+        presets.extend(imported_presets.get());
+        presets
+    };
+    let solar_system_count = load_presets().len();
+    let exoplanet_count = load_exoplanet_presets().len();
+    let preset_options = move |start: usize, end: usize| {
+        presets().into_iter().enumerate().take(end).skip(start).map(|(index, preset)| {
+            view! {
+                <option value={(index + 1).to_string()}>{preset.name}</option>
+            }
+        }).collect_view()
+    };
+
     view! {
         <div id="inputs">
+            <div class="input_section">
+                <div class="input_select input input_half">
+                    <LanguageSelect />
+                </div>
+                <div class="input_select input input_half">
+                    <ThemeSelect />
+                </div>
+            </div>
+            <UnitSettingsSelect />
             <div class="input_section">
                 <div class="input_select input">
-                    <span class="input_select_label">"Presets: "</span>
+                    <span class="input_select_label">{move || translate(language.get(), "Presets: ")}</span>
                     <select on:change=move |ev| {
                         let index = event_target_value(&ev).parse::<usize>().unwrap_or(0);
                         let input_sliders = document().get_elements_by_class_name("input_slider");
+                        set_preset_index.set(index);
 
                         if index == 0 {
                             for i in 0..input_sliders.length() {
                                 input_sliders.item(i).unwrap().class_list().remove_1("input_inactive").unwrap();
                             }
-                            
-                            planet_signal.1.set(create_planet(
-                                document().get_element_by_id("input_slider_a").unwrap().dyn_into::<web_sys::HtmlInputElement>().unwrap().value_as_number(),
-                                document().get_element_by_id("input_slider_e").unwrap().dyn_into::<web_sys::HtmlInputElement>().unwrap().value_as_number(),
-                                document().get_element_by_id("input_slider_m").unwrap().dyn_into::<web_sys::HtmlInputElement>().unwrap().value_as_number(),
-                                document().get_element_by_id("input_slider_d").unwrap().dyn_into::<web_sys::HtmlInputElement>().unwrap().value_as_number()
-                            ));
+
+                            planet_signal.1.set(create_planet(&custom_preset(planet_signal.0.get_untracked(), load_star_presets()[host_star.get_untracked()].clone())));
                         } else {
                             for i in 0..input_sliders.length() {
                                 input_sliders.item(i).unwrap().class_list().add_1("input_inactive").unwrap();
                             }
 
-                            let preset = presets.get(index - 1).cloned().unwrap_or_else(|| PlanetPreset {
-                                name: String::new(),
-                                m: 0.0,
-                                d: 0.0,
-                                a: 0.0,
-                                e: 0.0
-                            });
-                            planet_signal.1.set(create_planet(preset.a, preset.e, preset.m, preset.d));
+                            let preset = presets().get(index - 1).cloned().unwrap_or_default();
+                            planet_signal.1.set(create_planet(&preset));
                         }
                     }>
-                        <option value="0">"Custom"</option>
-                        {presets.iter().enumerate().map(|(index, preset)| {
+                        <option value="0">{move || translate(language.get(), "Custom")}</option>
+                        <optgroup label=move || translate(language.get(), "Solar System")>{preset_options(0, solar_system_count)}</optgroup>
+                        <optgroup label=move || translate(language.get(), "Exoplanets")>{preset_options(solar_system_count, solar_system_count + exoplanet_count)}</optgroup>
+                        <Show when=move || !imported_presets.get().is_empty()>
+                            <optgroup label=move || translate(language.get(), "Imported")>{move || preset_options(solar_system_count + exoplanet_count, usize::MAX)}</optgroup>
+                        </Show>
+                    </select>
+                </div>
+            </div>
+            <div class="input_section">
+                <div class="input_select input input_half">
+                    <span class="input_select_label">{move || translate(language.get(), "Host Star: ")}</span>
+                    <select disabled=move || preset_index.get() != 0 on:change=move |ev| {
+                        set_host_star.set(event_target_value(&ev).parse::<usize>().unwrap_or(0));
+                        planet_signal.1.set(create_planet(&custom_preset(planet_signal.0.get_untracked(), load_star_presets()[host_star.get_untracked()].clone())));
+                    }>
+                        {load_star_presets().into_iter().enumerate().map(|(index, star)| {
                             view! {
-                                <option value={(index + 1).to_string()}>{preset.name.clone()}</option>
+                                <option value={index.to_string()}>{star.name}</option>
                             }
                         }).collect_view()}
                     </select>
                 </div>
+                <div class="input_select input input_half">
+                    <span class="input_select_label">{move || translate(language.get(), "NASA Exoplanet Archive CSV: ")}</span>
+                    <input type="file" accept=".csv,text/csv" on:change=move |ev| {
+                        import_presets(ev, set_imported_presets, set_import_error);
+                    } />
+                    <Show when=move || !imported_presets.get().is_empty()>
+                        <button on:click=move |_| {
+                            clear_imported_presets();
+                            set_imported_presets.set(vec![]);
+                        }>{move || translate(language.get(), "Clear imported")}</button>
+                    </Show>
+                    {move || import_error.get().map(|e| view! {<span class="input_error">{e}</span>})}
+                </div>
             </div>
             <div class="input_section">
                 <div class="input_slider input">
-                    <span class="input_slider_label">"Semi-Major Axis"</span>
-                    <input id="input_slider_a" type="range" min="1" max="750.0" step="0.01" value={planet_signal.0.get_untracked().a_input.0.get_untracked()} on:input=move |ev| {
-                        update_planet_data(planet_signal.0.get(), planet_signal.1, ev, 0);
-                    } />
-                    <span class="input_slider_value">{move || format!("{:.2}", planet_signal.0.get().a_input.0.get())} "AU"</span>
+                    <span class="input_slider_label">{move || translate(language.get(), "Semi-Major Axis")}</span>
+                    <QuantityInput planet_signal=planet_signal index=0 id="a" label="Semi-Major Axis" kind=QuantityKind::Distance range=(0.01, 1000.0, 0.0) log=true limits=(1e-6, f64::INFINITY) />
+                    <span class="input_slider_value">{move || units.get().format_distance(planet_signal.0.get().a.0.get(), language.get())}</span>
                 </div>
                 <div class="input_slider input">
-                    <span class="input_slider_label">"Orbit Eccentricity"</span>
-                    <input id="input_slider_e" type="range" min="0" max=".99" step="0.01" value={planet_signal.0.get_untracked().e.0.get_untracked()} on:input=move |ev| {
-                        update_planet_data(planet_signal.0.get(), planet_signal.1, ev, 1);  
-                    } />
-                    <span class="input_slider_value">{move || format!("{:.2}", planet_signal.0.get().e.0.get())}</span>
+                    <span class="input_slider_label">{move || translate(language.get(), "Orbit Eccentricity")}</span>
+                    <QuantityInput planet_signal=planet_signal index=1 id="e" label="Orbit Eccentricity" kind=QuantityKind::Ratio range=(0.0, 0.99, 0.01) limits=(0.0, 0.9999) />
+                    <span class="input_slider_value">{move || format_number(language.get(), planet_signal.0.get().e.0.get(), 2)}</span>
                 </div>
             </div>
+            <OrbitParameters planet_signal=planet_signal editable=Signal::derive(move || preset_index.get() == 0) />
             <div class="input_section">
                 <div class="input_slider input">
-                    <span class="input_slider_label">"Object Mass"</span>
-                    <input id="input_slider_m" type="range" min="0.01" max="330" step="0.01" value={planet_signal.0.get_untracked().m_object.0.get_untracked()} on:input=move |ev| {
-                        update_planet_data(planet_signal.0.get(), planet_signal.1, ev, 2);
-                    } />
-                    <Show when=move || {planet_signal.0.get().m_object.0.get() != 0.0} fallback=|| {view!{<span class="input_slider_value">"Undefined"</span>}}>
-                        <span class="input_slider_value">{move || format!("{:.2}", planet_signal.0.get().m_object.0.get())} "M🜨"</span>
+                    <span class="input_slider_label">{move || translate(language.get(), "Object Mass")}</span>
+                    <QuantityInput planet_signal=planet_signal index=2 id="m" label="Object Mass" kind=QuantityKind::Mass range=(1e-4, 1e4, 0.0) log=true limits=(0.0, f64::INFINITY) />
+                    <Show when=move || {planet_signal.0.get().m_object.0.get() != 0.0} fallback=move || {view!{<span class="input_slider_value">{move || translate(language.get(), "Undefined")}</span>}}>
+                        <span class="input_slider_value">{move || format_number(language.get(), planet_signal.0.get().m_object.0.get(), 2)} "M🜨"</span>
                     </Show>
                 </div>
                 <div class="input_slider input">
-                    <span class="input_slider_label">"Object Diameter"</span>
-                    <input id="input_slider_d" type="range" min="0.01" max="12" step="0.01" value={planet_signal.0.get_untracked().d.0.get_untracked()} on:input=move |ev| {
-                        update_planet_data(planet_signal.0.get(), planet_signal.1, ev, 3);
-                    } />
-                    <span class="input_slider_value">{move || format!("{:.2}", planet_signal.0.get().d.0.get())} "D🜨"</span>
+                    <span class="input_slider_label">{move || translate(language.get(), "Object Diameter")}</span>
+                    <QuantityInput planet_signal=planet_signal index=3 id="d" label="Object Diameter" kind=QuantityKind::Diameter range=(1e-3, 30.0, 0.0) log=true limits=(1e-9, f64::INFINITY) />
+                    <span class="input_slider_value">{move || format_number(language.get(), planet_signal.0.get().d.0.get(), 2)} "D🜨"</span>
+                </div>
+            </div>
+            <div class="input_section">
+                <div class="input_slider input">
+                    <span class="input_slider_label">{move || translate(language.get(), "Rotation Period")}</span>
+                    <QuantityInput planet_signal=planet_signal index=4 id="rot" label="Rotation Period" kind=QuantityKind::Period range=(0.1, 1e4, 0.0) log=true limits=(0.0, f64::INFINITY) />
+                    <Show when=move || {planet_signal.0.get().rotation_period.0.get() != 0.0} fallback=move || {view!{<span class="input_slider_value">{move || translate(language.get(), "Undefined")}</span>}}>
+                        <span class="input_slider_value">{move || format_number(language.get(), planet_signal.0.get().rotation_period.0.get(), 2)} " h"</span>
+                    </Show>
+                </div>
+                <div class="input_slider input">
+                    <span class="input_slider_label">{move || translate(language.get(), "Axial Tilt")}</span>
+                    <QuantityInput planet_signal=planet_signal index=5 id="tilt" label="Axial Tilt" kind=QuantityKind::Angle range=(0.0, 180.0, 0.1) limits=(0.0, 180.0) />
+                    <span class="input_slider_value">{move || format_number(language.get(), planet_signal.0.get().axial_tilt.0.get(), 1)} "°"</span>
+                </div>
+            </div>
+            <div class="input_section">
+                <div class="input_slider input">
+                    <span class="input_slider_label">{move || translate(language.get(), "Inclination to the Sky")}</span>
+                    <QuantityInput planet_signal=planet_signal index=6 id="i" label="Inclination to the Sky" kind=QuantityKind::Angle range=(0.0, 180.0, 0.01) limits=(0.0, 180.0) />
+                    <span class="input_slider_value">{move || format_number(language.get(), planet_signal.0.get().inclination.0.get(), 2)} "°"</span>
+                </div>
+                <div class="input_slider input">
+                    <span class="input_slider_label">{move || translate(language.get(), "Argument of Periapsis")}</span>
+                    <QuantityInput planet_signal=planet_signal index=7 id="w" label="Argument of Periapsis" kind=QuantityKind::Angle range=(0.0, 360.0, 0.1) limits=(0.0, 360.0) />
+                    <span class="input_slider_value">{move || format_number(language.get(), planet_signal.0.get().argument_periapsis.0.get(), 1)} "°"</span>
                 </div>
             </div>
         </div>
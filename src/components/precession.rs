@@ -0,0 +1,105 @@
+use leptos::prelude::*;
+
+use crate::utils::{orbital_period::calculate_orbital_period, precession::{calculate_apsidal_precession, calculate_nodal_precession, calculate_relativistic_precession, calculate_sun_synchronous_inclination, convert_to_arcsec_per_century}, units::SECONDS_PER_DAY, i18n::{fill_template, format_number, format_scientific, translate}};
+use super::{planet::PlanetData, unit_settings::use_unit_settings, language::use_language};
+use super::switch_view::is_view_active;
+
+#[component]
+fn RelativisticPrecession(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    let per_orbit = move || calculate_relativistic_precession(planet.get().a.0.get(), planet.get().e.0.get(), planet.get().m_sun, planet.get().g);
+    let period = move || calculate_orbital_period(planet.get().a.0.get(), planet.get().g, planet.get().m_object.0.get(), planet.get().m_sun, planet.get().m_earth);
+
+    view! {
+        <div class="small_property">
+            <span>{move || translate(language.get(), "Relativistic Perihelion Advance: ")} {move || fill_template(
+                translate(language.get(), "{}″ per century ({}″ per orbit)"),
+                &[format_number(language.get(), convert_to_arcsec_per_century(per_orbit(), period()), 3), format_scientific(language.get(), per_orbit().to_degrees() * 3600.0, 3)],
+            )}</span>
+        </div>
+    }
+}
+
+#[component]
+fn SatellitePrecession(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    let units = use_unit_settings();
+    // Defaults of a typical earth observation satellite
+    let (altitude, set_altitude) = signal(700.0_f64);
+    let (eccentricity, set_eccentricity) = signal(0.0_f64);
+    let (inclination, set_inclination) = signal(98.0_f64);
+
+    let radius = move || planet.get().d.0.get() * planet.get().d_earth * 1000.0 / 2.0;
+    let mass = move || planet.get().m_object.0.get() * planet.get().m_earth;
+    let a_satellite = move || radius() + altitude.get() * 1000.0;
+    let per_day = move |rate: f64| format_number(language.get(), units.get().angle(rate * SECONDS_PER_DAY), 4);
+
+    let nodal = move || calculate_nodal_precession(planet.get().j2.0.get(), radius(), mass(), planet.get().g, a_satellite(), eccentricity.get(), inclination.get().to_radians());
+    let apsidal = move || calculate_apsidal_precession(planet.get().j2.0.get(), radius(), mass(), planet.get().g, a_satellite(), eccentricity.get(), inclination.get().to_radians());
+    let sun_synchronous = move || calculate_sun_synchronous_inclination(
+        planet.get().j2.0.get(),
+        radius(),
+        mass(),
+        planet.get().g,
+        a_satellite(),
+        eccentricity.get(),
+        calculate_orbital_period(planet.get().a.0.get(), planet.get().g, planet.get().m_object.0.get(), planet.get().m_sun, planet.get().m_earth),
+    );
+
+    view! {
+        <div class="small_property satellite_inputs">
+            <span>{move || translate(language.get(), "Satellite around the object")}</span>
+            <label>"J2: "
+                <input type="number" min="0" step="0.0001" prop:value=move || planet.get().j2.0.get() on:input=move |ev| {
+                    planet.get().j2.1.set(event_target_value(&ev).parse::<f64>().unwrap_or(0.0));
+                } />
+            </label>
+            <label>{move || format!("{} ({}): ", translate(language.get(), "Altitude"), units.get().length_label())}
+                <input type="number" min="0" step="10" prop:value=move || units.get().length(altitude.get()) on:input=move |ev| {
+                    set_altitude.set(units.get_untracked().parse_length(event_target_value(&ev).parse::<f64>().unwrap_or(0.0)));
+                } />
+            </label>
+            <label>{move || translate(language.get(), "Eccentricity: ")}
+                <input type="number" min="0" max="0.99" step="0.01" prop:value=move || eccentricity.get() on:input=move |ev| {
+                    set_eccentricity.set(event_target_value(&ev).parse::<f64>().unwrap_or(0.0).clamp(0.0, 0.99));
+                } />
+            </label>
+            <label>{move || format!("{} ({}): ", translate(language.get(), "Inclination"), units.get().angle_label())}
+                <input type="number" min="0" max=move || units.get().angle(180_f64.to_radians()) step="any" prop:value=move || units.get().angle(inclination.get().to_radians()) on:input=move |ev| {
+                    set_inclination.set(units.get_untracked().parse_angle(event_target_value(&ev).parse::<f64>().unwrap_or(0.0)).to_degrees());
+                } />
+            </label>
+        </div>
+        <Show when=move || {planet.get().j2.0.get() != 0.0} fallback=move || view!{
+            <div class="small_property">{move || translate(language.get(), "The oblateness (J2) of this object is unknown.")}</div>
+        }>
+            <div class="small_property">
+                <span>{move || format!("{} ({}/{}): ", translate(language.get(), "Nodal Precession"), units.get().angle_label(), translate(language.get(), "day"))} {move || per_day(nodal())}</span>
+                <br/>
+                <span>{move || format!("{} ({}/{}): ", translate(language.get(), "Apsidal Precession"), units.get().angle_label(), translate(language.get(), "day"))} {move || per_day(apsidal())}</span>
+                <br/>
+                <span>{move || match sun_synchronous() {
+                    Some(inclination) => fill_template(translate(language.get(), "Sun-synchronous at an inclination of {}"), &[units.get().format_angle(inclination, language.get())]),
+                    None => String::from(translate(language.get(), "No sun-synchronous orbit at this altitude")),
+                }}</span>
+            </div>
+        </Show>
+    }
+}
+
+//Relativistic perihelion advance of the orbit and J2 precession of satellites around the object
+#[component]
+pub fn Precession(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    let active = is_view_active("precession");
+    view! {
+        <div id="precession" class="tool_panel view" class:invisible_element=move || !active.get()>
+            <RelativisticPrecession planet={planet} />
+            <Show when=move || {planet.get().m_object.0.get() != 0.0} fallback=move || view!{
+                <div class="small_property">{move || translate(language.get(), "Satellite precession is missing, due to the mass being unknown.")}</div>
+            }>
+                <SatellitePrecession planet={planet} />
+            </Show>
+        </div>
+    }
+}
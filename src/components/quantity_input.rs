@@ -0,0 +1,73 @@
+use leptos::prelude::*;
+
+use crate::utils::{i18n::translate, quantity::{format_quantity, parse_quantity, QuantityError, QuantityKind}};
+use super::{language::use_language, planet::{read_planet_data, update_planet_data, PlanetData}, unit_settings::use_unit_settings};
+
+// Slider position of a value, logarithmic sliders move by powers of ten
+fn slider_position(value: f64, log: bool) -> f64 {
+    if log {
+        value.max(f64::MIN_POSITIVE).log10()
+    } else {
+        value
+    }
+}
+
+fn slider_value(position: f64, log: bool) -> f64 {
+    if log {
+        10.0_f64.powf(position)
+    } else {
+        position
+    }
+}
+
+// Slider and text field of one planet value, the text field also accepts values outside the slider range
+// range is (min, max, step) of the slider in the unit of the quantity, logarithmic sliders move continuously
+#[component]
+pub fn QuantityInput(
+    planet_signal: (ReadSignal<PlanetData>, WriteSignal<PlanetData>),
+    index: i8,
+    id: &'static str,
+    label: &'static str,
+    kind: QuantityKind,
+    range: (f64, f64, f64),
+    #[prop(optional)] log: bool,
+    limits: (f64, f64),
+) -> impl IntoView {
+    let language = use_language();
+    let units = use_unit_settings();
+    let (error, set_error) = signal(None::<QuantityError>);
+    let value = move || read_planet_data(planet_signal.0.get(), index);
+
+    let (min, max, step) = range;
+    let step = if log { String::from("any") } else { step.to_string() };
+
+    view! {
+        <input id={format!("input_slider_{}", id)} type="range"
+            min={slider_position(min, log)} max={slider_position(max, log)} step={step}
+            aria-label=move || translate(language.get(), label)
+            prop:value=move || slider_position(value(), log)
+            on:input=move |ev| {
+                let position = event_target_value(&ev).parse::<f64>().unwrap_or(slider_position(min, log));
+                set_error.set(None);
+                update_planet_data(planet_signal.0.get(), planet_signal.1, slider_value(position, log), index);
+            } />
+        <input id={format!("input_number_{}", id)} class="input_number" type="text" inputmode="decimal" spellcheck="false"
+            aria-label=move || translate(language.get(), label)
+            aria-invalid=move || error.get().is_some().to_string()
+            prop:value=move || format_quantity(value(), kind, units.get(), language.get())
+            on:input=move |ev| {
+                // Errors show while typing, the value is only taken on enter or leaving the field
+                set_error.set(parse_quantity(&event_target_value(&ev), kind, limits, units.get(), language.get()).err());
+            }
+            on:change=move |ev| {
+                match parse_quantity(&event_target_value(&ev), kind, limits, units.get(), language.get()) {
+                    Ok(parsed) => {
+                        set_error.set(None);
+                        update_planet_data(planet_signal.0.get(), planet_signal.1, parsed, index);
+                    },
+                    Err(e) => set_error.set(Some(e)),
+                }
+            } />
+        {move || error.get().map(|e| view! {<span class="input_error" role="alert">{e.message(language.get())}</span>})}
+    }
+}
\ No newline at end of file
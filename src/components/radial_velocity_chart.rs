@@ -0,0 +1,87 @@
+use leptos::prelude::*;
+use leptos_chartistry::*;
+use leptos_chartistry::IntoInner;
+use std::f64::consts::PI;
+
+use crate::utils::{kepler::calculate_true_anomaly, orbital_period::calculate_orbital_period, radial_velocity::{calculate_radial_velocity, calculate_radial_velocity_amplitude}};
+use super::{planet::PlanetData, language::translated_title, chart_table::ChartTable};
+use super::switch_view::is_view_active;
+
+#[derive(Clone, PartialEq)]
+struct ChartData {
+    x: f64,
+    y: f64
+}
+
+impl ChartData {
+    fn add(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+fn get_amplitude(planet: &PlanetData) -> f64 {
+    calculate_radial_velocity_amplitude(
+        planet.m_object.0.get(),
+        planet.m_earth,
+        planet.m_sun,
+        planet.g,
+        calculate_orbital_period(planet.a.0.get(), planet.g, planet.m_object.0.get(), planet.m_sun, planet.m_earth),
+        planet.e.0.get(),
+        planet.inclination.0.get().to_radians(),
+    )
+}
+
+// Radial velocity over one orbit, starting at the periapsis, with the time as orbital phase
+fn get_chart_data(planet: &PlanetData, start: f64, end: f64, step: f64) -> Vec<ChartData> {
+    let amplitude = get_amplitude(planet);
+    let e = planet.e.0.get();
+    let argument_periapsis = planet.argument_periapsis.0.get().to_radians();
+
+    let mut data = Vec::new();
+    let mut phase = start;
+    while phase < end {
+        let angle = calculate_true_anomaly(2.0 * PI * phase, e);
+        data.push(ChartData::add(phase, calculate_radial_velocity(amplitude, e, argument_periapsis, angle)));
+        phase += step;
+    }
+    data
+}
+
+#[component]
+pub fn RadialVelocityChart(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let chart_data = Memo::new(move |_| get_chart_data(&planet.get(), 0.0, 1.0, 0.002));
+
+    let series = Series::new(|data: &ChartData| data.x)
+        .line(Line::new(|data: &ChartData| data.y).with_name("Radial Velocity (m/s):"))
+        .with_x_range(0.0, 1.0);
+
+    let active = is_view_active("radial_velocity_chart");
+    view! {
+        <div id="radial_velocity_chart" class="view" class:invisible_element=move || !active.get()>
+            <Show when=move || planet.get().m_object.0.get() != 0.0
+                fallback=|| view!{<div class="small_property">"Mass is undefined"</div>}>
+                <Chart
+                    aspect_ratio=AspectRatio::from_env()
+                    series=series.clone()
+                    data=chart_data
+                    top=translated_title("Radial Velocity of the Star")
+                    left=vec![RotatedLabel::end("Radial Velocity (m/s)").into(), TickLabels::aligned_floats().into()]
+                    bottom=vec![TickLabels::aligned_floats().into(), RotatedLabel::end("Orbital phase from periapsis").into()]
+                    inner=vec![
+                        AxisMarker::left_edge().into_inner(),
+                        AxisMarker::bottom_edge().into_inner(),
+                        XGridLine::default().into_inner(),
+                        YGridLine::default().into_inner(),
+                        YGuideLine::over_mouse().into_inner(),
+                        XGuideLine::over_data().into_inner(),
+                    ]
+                />
+                <ChartTable
+                    columns=Signal::derive(|| vec![String::from("Orbital phase from periapsis"), String::from("Radial Velocity (m/s)")])
+                    rows=Signal::derive(move || chart_data.get().iter().map(|data| vec![data.x, data.y]).collect())
+                />
+                <span class="note">{move || format!("Semi-amplitude K = {:.3} m/s", get_amplitude(&planet.get()))}</span>
+            </Show>
+        </div>
+    }
+}
@@ -1,57 +1,222 @@
 use leptos::prelude::*;
 
-use crate::utils::{escape_velocity::calculate_escape_velocity, gravitational_acceleration::calculate_gravitational_acceleration, orbital_period::calculate_orbital_period, lift_object::calculate_lift_energy};
-use super::planet::PlanetData;
+use crate::utils::{escape_velocity::calculate_escape_velocity, gravitational_acceleration::calculate_gravitational_acceleration, orbital_period::calculate_orbital_period, lift_object::{calculate_lift_energy, calculate_orbit_insertion_energy, calculate_orbit_insertion_delta_v}, solar_day::calculate_solar_day, synchronous_orbit::calculate_synchronous_orbit_radius, effective_gravity::{calculate_equatorial_gravity, calculate_polar_gravity}, stellar_flux::{calculate_stellar_flux, calculate_equilibrium_temperature}, density::calculate_density, i18n::{format_number, format_scientific, translate}, units::{UnitSettings, SOLAR_LUMINOSITY, SOLAR_MASS, SOLAR_RADIUS}};
+use super::{planet::PlanetData, orbit_visualization_options::ComparisonBody, unit_settings::use_unit_settings, language::use_language};
+use super::switch_view::is_view_active;
 
 
 #[component]
 fn EscapeVelocity(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    let units = use_unit_settings();
+
     view! {
         <div class="small_property">
-            <span>"Escape Velocity: " {move || format!("{:.3}", calculate_escape_velocity(planet.get().d.0.get(), planet.get().d_earth, planet.get().m_object.0.get(), planet.get().m_earth))} " km/s"</span>
+            <span>{move || translate(language.get(), "Escape Velocity: ")} {move || units.get().format_speed(calculate_escape_velocity(planet.get().d.0.get(), planet.get().d_earth, planet.get().m_object.0.get(), planet.get().m_earth), language.get())}</span>
         </div>
     }
 }
 
 #[component]
 fn GravitationalAcceleration(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    let units = use_unit_settings();
+
     view! {
         <div class="small_property">
-            <span>"Gravitational Acceleration: " {move || format!("{:.4}", calculate_gravitational_acceleration(planet.get().d.0.get(), planet.get().m_object.0.get(), planet.get().m_earth))} " m/(s²)"</span>
+            <span>{move || translate(language.get(), "Gravitational Acceleration: ")} {move || units.get().format_acceleration(calculate_gravitational_acceleration(planet.get().d.0.get(), planet.get().m_object.0.get(), planet.get().m_earth), language.get())}</span>
         </div>
     }
 }
 
 #[component]
-fn LiftEnergy(planet: ReadSignal<PlanetData>) -> impl IntoView {
+fn LiftEnergy(planet: ReadSignal<PlanetData>, lift_parameters: ReadSignal<(f64, f64)>, set_lift_parameters: WriteSignal<(f64, f64)>) -> impl IntoView {
+    let language = use_language();
+    let units = use_unit_settings();
+
     view! {
+        <div class="small_property satellite_inputs">
+            <label>{move || translate(language.get(), "Payload mass (kg): ")}
+                <input type="number" min="0" step="100" prop:value=move || lift_parameters.get().0 on:input=move |ev| {
+                    let mass = event_target_value(&ev).parse::<f64>().unwrap_or(0.0).max(0.0);
+                    set_lift_parameters.update(|parameters| parameters.0 = mass);
+                } />
+            </label>
+            <label>{move || format!("{} ({}): ", translate(language.get(), "Altitude"), units.get().length_label())}
+                <input type="number" min="0" step="50" prop:value=move || units.get().length(lift_parameters.get().1) on:input=move |ev| {
+                    let altitude = units.get_untracked().parse_length(event_target_value(&ev).parse::<f64>().unwrap_or(0.0).max(0.0));
+                    set_lift_parameters.update(|parameters| parameters.1 = altitude);
+                } />
+            </label>
+        </div>
         <div class="small_property">
-            <span>"Work required to lift an 1000kg object from the surface to a 750km altitude: " {move || format!("{:.2e}", calculate_lift_energy(planet.get().d.0.get(), planet.get().d_earth, planet.get().m_object.0.get(), planet.get().m_earth, planet.get().g))} " J"</span>
+            <span>{move || translate(language.get(), "Work required to lift the payload from the surface to the altitude: ")} {move || format_scientific(language.get(), calculate_lift_energy(planet.get().d.0.get(), planet.get().d_earth, planet.get().m_object.0.get(), planet.get().m_earth, planet.get().g, lift_parameters.get().0, lift_parameters.get().1 * 1000.0), 2)} " J"</span>
+            <br/>
+            <span>{move || translate(language.get(), "Energy to reach a circular orbit at the altitude: ")} {move || format_scientific(language.get(), calculate_orbit_insertion_energy(planet.get().d.0.get(), planet.get().d_earth, planet.get().m_object.0.get(), planet.get().m_earth, planet.get().g, lift_parameters.get().0, lift_parameters.get().1 * 1000.0), 2)} {move || translate(language.get(), " J (ideal Δv ")}
+                {move || units.get().format_speed(calculate_orbit_insertion_delta_v(planet.get().d.0.get(), planet.get().d_earth, planet.get().m_object.0.get(), planet.get().m_earth, planet.get().g, lift_parameters.get().1 * 1000.0) / 1000.0, language.get())} ")"</span>
         </div>
     }
 }
 
 #[component]
 fn RotationalPeriod(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    let units = use_unit_settings();
+
     view! {
         <div class="small_property">
-            <span>"Orbital Period: " {move || format!("{:.2}", calculate_orbital_period(planet.get().a.0.get(), planet.get().g, planet.get().m_object.0.get(), planet.get().m_sun, planet.get().m_earth))} " years"</span>
+            <span>{move || translate(language.get(), "Orbital Period: ")} {move || units.get().format_time(calculate_orbital_period(planet.get().a.0.get(), planet.get().g, planet.get().m_object.0.get(), planet.get().m_sun, planet.get().m_earth), language.get())}</span>
         </div>
     }
 }
 
 #[component]
-pub fn SmallProperties(planet: ReadSignal<PlanetData>) -> impl IntoView {
+fn DayLength(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    let solar_day = move || calculate_solar_day(
+        planet.get().rotation_period.0.get(),
+        planet.get().axial_tilt.0.get(),
+        calculate_orbital_period(planet.get().a.0.get(), planet.get().g, planet.get().m_object.0.get(), planet.get().m_sun, planet.get().m_earth),
+    );
+
+    view! {
+        <div class="small_property">
+            <span>{move || translate(language.get(), "Sidereal Day: ")} {move || format_number(language.get(), planet.get().rotation_period.0.get(), 2)} " h"</span>
+            <br/>
+            <Show when=move || solar_day().is_finite() fallback=move || view!{<span>{move || translate(language.get(), "Solar Day: none (tidally locked)")}</span>}>
+                <span>{move || translate(language.get(), "Solar Day: ")} {move || format_number(language.get(), solar_day(), 2)} " h (" {move || format_number(language.get(), solar_day() / 24.0, 2)} {move || translate(language.get(), " days)")}</span>
+            </Show>
+            <br/>
+            <span>{move || translate(language.get(), "Axial Tilt: ")} {move || format_number(language.get(), planet.get().axial_tilt.0.get(), 2)} "°" {move || if planet.get().axial_tilt.0.get() > 90.0 {translate(language.get(), " (retrograde rotation)")} else {""}}</span>
+        </div>
+    }
+}
+
+#[component]
+fn SynchronousOrbit(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    let radius = move || calculate_synchronous_orbit_radius(planet.get().rotation_period.0.get(), planet.get().m_object.0.get(), planet.get().m_earth, planet.get().g);
+    let altitude = move || radius() - planet.get().d.0.get() * planet.get().d_earth * 1000.0 / 2.0;
+
     view! {
-        <div id="small_properties" class="invisible_element">
-            <Show when=move || {planet.get().m_object.0.get() != 0.0} fallback=|| view!{
-                <div class="small_property">"Some properties are missing, due to the mass being unknown."</div>
+        <div class="small_property">
+            <Show when=move || {altitude() > 0.0} fallback=move || view!{<span>{move || translate(language.get(), "Synchronous Orbit: below the surface")}</span>}>
+                <span>{move || translate(language.get(), "Synchronous Orbit: ")} {move || format_number(language.get(), radius() / 1000.0, 0)} {move || translate(language.get(), " km radius, ")} {move || format_number(language.get(), altitude() / 1000.0, 0)} {move || translate(language.get(), " km altitude")}</span>
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+fn EffectiveGravity(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    let units = use_unit_settings();
+
+    view! {
+        <div class="small_property">
+            <span>{move || translate(language.get(), "Effective Gravity: ")} {move || units.get().format_acceleration(calculate_equatorial_gravity(planet.get().d.0.get(), planet.get().d_earth, planet.get().m_object.0.get(), planet.get().m_earth, planet.get().rotation_period.0.get()), language.get())} {move || translate(language.get(), " at the equator, ")}
+                {move || units.get().format_acceleration(calculate_polar_gravity(planet.get().d.0.get(), planet.get().m_object.0.get(), planet.get().m_earth), language.get())} {move || translate(language.get(), " at the poles")}</span>
+        </div>
+    }
+}
+
+#[component]
+fn HostStar(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    view! {
+        <div class="small_property">
+            <span>{move || translate(language.get(), "Host Star: ")} {move || format_number(language.get(), planet.get().m_sun / SOLAR_MASS, 3)} " M☉, "
+                {move || format_number(language.get(), planet.get().r_sun / SOLAR_RADIUS, 3)} " R☉, "
+                {move || format_scientific(language.get(), planet.get().l_sun / SOLAR_LUMINOSITY, 3)} " L☉, "
+                {move || format_number(language.get(), planet.get().t_sun, 0)} " K"</span>
+        </div>
+    }
+}
+
+#[component]
+fn StellarFlux(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let language = use_language();
+    // Flux received by Earth from the Sun
+    let solar_constant = 1361.0;
+
+    view! {
+        <div class="small_property">
+            <span>{move || translate(language.get(), "Stellar Flux: ")} {move || format_number(language.get(), calculate_stellar_flux(planet.get().l_sun, planet.get().a.0.get()) / solar_constant, 3)} " S🜨, "
+                {move || translate(language.get(), "Equilibrium Temperature: ")} {move || format_number(language.get(), calculate_equilibrium_temperature(planet.get().l_sun, planet.get().a.0.get(), 0.0), 0)} {move || translate(language.get(), " K (zero albedo)")}</span>
+        </div>
+    }
+}
+
+// Properties compared side by side as (name, unit, value), the value is NaN if unknown
+fn get_compared_properties(planet: &PlanetData, units: UnitSettings) -> [(&'static str, &'static str, f64); 7] {
+    let (d, m) = (planet.d.0.get(), planet.m_object.0.get());
+    let with_mass = |value: f64| if m != 0.0 && d != 0.0 { value } else { f64::NAN };
+
+    [
+        ("Mass", "Earth masses", if m != 0.0 { m } else { f64::NAN }),
+        ("Diameter", "Earth diameters", d),
+        ("Surface gravity", units.acceleration_label(), with_mass(units.acceleration(calculate_gravitational_acceleration(d, m, planet.m_earth)))),
+        ("Escape velocity", units.speed_label(), with_mass(units.speed(calculate_escape_velocity(d, planet.d_earth, m, planet.m_earth)))),
+        ("Density", "kg/m³", with_mass(calculate_density(d, planet.d_earth, m, planet.m_earth))),
+        ("Semi-major axis", units.distance_label(), units.distance(planet.a.0.get())),
+        ("Orbital period", units.time_label(), units.time(calculate_orbital_period(planet.a.0.get(), planet.g, m, planet.m_sun, planet.m_earth))),
+    ]
+}
+
+#[component]
+fn ComparisonProperties(planet: ReadSignal<PlanetData>, comparison: ReadSignal<Option<ComparisonBody>>) -> impl IntoView {
+    let language = use_language();
+    let format_value = move |value: f64| if value.is_nan() { String::from("–") } else { format_number(language.get(), value, 4) };
+    let units = use_unit_settings();
+
+    view! {
+        {move || comparison.get().map(|comparison| view! {
+            <div class="small_property comparison_properties">
+                <span>{move || translate(language.get(), "Property")}</span>
+                <span>{move || translate(language.get(), "Current object")}</span>
+                <span>{comparison.name.clone()}</span>
+                <span>{move || translate(language.get(), "Ratio")}</span>
+                {get_compared_properties(&planet.get(), units.get()).into_iter().zip(get_compared_properties(&comparison.planet, units.get())).map(|((name, unit, value), (_, _, compared_value))| view! {
+                    <span>{format!("{} ({})", translate(language.get(), name), translate(language.get(), unit))}</span>
+                    <span>{format_value(value)}</span>
+                    <span>{format_value(compared_value)}</span>
+                    <span>{if value.is_nan() || compared_value.is_nan() || compared_value == 0.0 {
+                        String::from("–")
+                    } else {
+                        format!("{}× {}", format_number(language.get(), value / compared_value, 2), comparison.name)
+                    }}</span>
+                }).collect_view()}
+            </div>
+        })}
+    }
+}
+
+#[component]
+pub fn SmallProperties(planet: ReadSignal<PlanetData>, lift_parameters: ReadSignal<(f64, f64)>, set_lift_parameters: WriteSignal<(f64, f64)>, comparison: ReadSignal<Option<ComparisonBody>>) -> impl IntoView {
+    let language = use_language();
+    let active = is_view_active("small_properties");
+    view! {
+        <div id="small_properties" class="view" class:invisible_element=move || !active.get()>
+            <ComparisonProperties planet={planet} comparison={comparison} />
+            <Show when=move || {planet.get().m_object.0.get() != 0.0} fallback=move || view!{
+                <div class="small_property">{move || translate(language.get(), "Some properties are missing, due to the mass being unknown.")}</div>
             }>
                 <EscapeVelocity planet={planet} />
                 <GravitationalAcceleration planet={planet} />
-                <LiftEnergy planet={planet} />
+                <LiftEnergy planet={planet} lift_parameters={lift_parameters} set_lift_parameters={set_lift_parameters} />
             </Show>
             <RotationalPeriod planet={planet} />
+            <HostStar planet={planet} />
+            <StellarFlux planet={planet} />
+            <Show when=move || {planet.get().rotation_period.0.get() != 0.0} fallback=move || view!{
+                <div class="small_property">{move || translate(language.get(), "Rotation properties are missing, due to the rotation period being unknown.")}</div>
+            }>
+                <DayLength planet={planet} />
+                <Show when=move || {planet.get().m_object.0.get() != 0.0}>
+                    <SynchronousOrbit planet={planet} />
+                    <EffectiveGravity planet={planet} />
+                </Show>
+            </Show>
         </div>
     }
 }
\ No newline at end of file
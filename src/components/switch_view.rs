@@ -1,50 +1,152 @@
 use icondata as i;
 use leptos::prelude::*;
 use leptos_icons::Icon;
+use leptos_router::{components::A, hooks::{use_navigate, use_params_map}, NavigateOptions};
+use wasm_bindgen::JsCast;
+use web_sys::Element;
 
-use crate::components::orbit_visualization::draw_scene;
-use super::planet::PlanetData;
+use crate::utils::i18n::translate;
+use super::language::use_language;
 
-fn switch_view(left_right: bool, planet: PlanetData, set_mouse_properties: WriteSignal<(bool, f64, f64, f64, f64)>) {
-    let container_ids = ["orbit_visualization_canvas", "velocity_chart", "gravitational_force_with_sun_chart", "small_properties"];
+// (path, element id, tab label) of the views, the first one is shown at the root
+pub const VIEWS: [(&str, &str, &str); 17] = [
+    ("", "orbit_visualization_canvas", "Orbit"),
+    ("velocity", "velocity_chart", "Velocity"),
+    ("force", "gravitational_force_with_sun_chart", "Force"),
+    ("transit", "transit_light_curve_chart", "Transit"),
+    ("radial-velocity", "radial_velocity_chart", "Radial velocity"),
+    ("lift-energy", "lift_energy_chart", "Lift energy"),
+    ("gravity-profile", "gravity_profile_chart", "Gravity profile"),
+    ("chart-builder", "chart_builder", "Chart builder"),
+    ("properties", "small_properties", "Properties"),
+    ("comparison", "comparison_table", "Comparison"),
+    ("synodic", "synodic_comparison", "Synodic periods"),
+    ("precession", "precession", "Precession"),
+    ("three-body", "three_body_view", "Three bodies"),
+    ("flyby", "flyby", "Flyby"),
+    ("launch", "launch_delta_v", "Launch Δv"),
+    ("delta-v-budget", "delta_v_budget", "Δv budget"),
+    ("orbit-options", "orbit_visualization_options", "Orbit options"),
+];
 
-    let current_active = document().get_elements_by_class_name("visible_element").get_with_index(0).unwrap();
+// A swipe has to be quick and mostly horizontal, slower drags probe the orbit
+const SWIPE_MIN_DISTANCE: f64 = 60.0;
+const SWIPE_MAX_DURATION: f64 = 400.0;
 
-    let current_active_index = container_ids.iter().position(|&r| r == current_active.id()).unwrap();
+// Index in VIEWS of the view selected by the route, None for unknown paths
+#[derive(Clone, Copy)]
+pub struct ActiveView(Memo<Option<usize>>);
 
-    let new_active_index = if left_right {
-        if current_active_index == 0 {
-            container_ids.len() - 1
-        } else {
-            current_active_index - 1
-        }
-    } else {
-        if current_active_index == container_ids.len() - 1 {
-            0
-        } else {
-            current_active_index + 1
-        }
-    };
+pub fn provide_active_view() {
+    let params = use_params_map();
+    let active = Memo::new(move |_| {
+        let path = params.with(|params| params.get("view")).unwrap_or_default();
+        VIEWS.iter().position(|(view_path, _, _)| *view_path == path)
+    });
+    provide_context(ActiveView(active));
+}
+
+pub fn use_active_view() -> Memo<Option<usize>> {
+    expect_context::<ActiveView>().0
+}
 
-    //Redraw canvas if its selected, otherwise remove hover-info
-    if new_active_index == 0 {
-        draw_scene(planet, signal((false, 0.0, 0.0, 0.0, 0.0)).1, false, std::rc::Rc::new(std::cell::RefCell::new(None)));
+// Whether the view with the element id is the selected one
+pub fn is_view_active(id: &'static str) -> Memo<bool> {
+    let active = use_active_view();
+    Memo::new(move |_| active.get().map(|index| VIEWS[index].1) == Some(id))
+}
+
+fn view_href(index: usize) -> String {
+    format!("/{}", VIEWS[index].0)
+}
+
+// Path of the view before or after the active one, wrapping around
+fn neighbour_href(active: Option<usize>, left: bool) -> String {
+    let index = active.unwrap_or(0);
+    let neighbour = if left {
+        (index + VIEWS.len() - 1) % VIEWS.len()
     } else {
-        set_mouse_properties((false, 0.0, 0.0, 0.0, 0.0));
-    }
+        (index + 1) % VIEWS.len()
+    };
+    view_href(neighbour)
+}
 
-    current_active.class_list().remove_1("visible_element").unwrap();
-    current_active.class_list().add_1("invisible_element").unwrap();
-    if let Some(new_active) = document().get_element_by_id(container_ids[new_active_index]) {
-        new_active.class_list().remove_1("invisible_element").unwrap();
-        new_active.class_list().add_1("visible_element").unwrap();
-    }
+// Arrows typed into inputs move their value, not the view
+fn is_editing(target: Option<web_sys::EventTarget>) -> bool {
+    target
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .is_some_and(|element| matches!(element.tag_name().as_str(), "INPUT" | "SELECT" | "TEXTAREA"))
 }
 
 #[component]
-pub fn SwitchView(planet: ReadSignal<PlanetData>, set_mouse_properties: WriteSignal<(bool, f64, f64, f64, f64)>) -> impl IntoView {
+pub fn SwitchView() -> impl IntoView {
+    let active = use_active_view();
+    let language = use_language();
+    let navigate = use_navigate();
+    let go_to = move |left: bool| navigate(&neighbour_href(active.get_untracked(), left), NavigateOptions::default());
+
+    // Views share one cell on phones, on wider screens the selected view is scrolled to
+    Effect::new(move |_| {
+        if let Some(element) = active.get().and_then(|index| document().get_element_by_id(VIEWS[index].1)) {
+            element.scroll_into_view();
+        }
+    });
+
+    let go_to_key = go_to.clone();
+    let _ = window_event_listener(leptos::ev::keydown, move |ev| {
+        if ev.alt_key() || ev.ctrl_key() || ev.meta_key() || is_editing(ev.target()) {
+            return;
+        }
+        match ev.key().as_str() {
+            "ArrowLeft" => go_to_key(true),
+            "ArrowRight" => go_to_key(false),
+            _ => {}
+        }
+    });
+
+    // (x, y, time stamp) of the finger that started the swipe on the active view
+    let swipe_start = StoredValue::new(None::<(f64, f64, f64)>);
+
+    let _ = window_event_listener(leptos::ev::pointerdown, move |ev| {
+        if ev.pointer_type() != "touch" {
+            return;
+        }
+        let on_view = ev.target()
+            .and_then(|target| target.dyn_into::<Element>().ok())
+            .and_then(|target| target.closest(".view").ok().flatten())
+            .is_some();
+        // A second finger is a pinch, not a swipe
+        let start = (ev.is_primary() && on_view).then(|| (ev.client_x() as f64, ev.client_y() as f64, ev.time_stamp()));
+        swipe_start.set_value(start);
+    });
+
+    let go_to_swipe = go_to.clone();
+    let go_to_left = go_to.clone();
+    let _ = window_event_listener(leptos::ev::pointerup, move |ev| {
+        if !ev.is_primary() {
+            return;
+        }
+        if let Some((x, y, time_stamp)) = swipe_start.get_value() {
+            swipe_start.set_value(None);
+            let dx = ev.client_x() as f64 - x;
+            let dy = ev.client_y() as f64 - y;
+            if dx.abs() > SWIPE_MIN_DISTANCE && dx.abs() > 2.0 * dy.abs() && ev.time_stamp() - time_stamp < SWIPE_MAX_DURATION {
+                // Swiping to the right shows the view on the left
+                go_to_swipe(dx > 0.0);
+            }
+        }
+    });
+
     view! {
-        <button class="arrow" id="arrow_left" on:click=move |_| {switch_view(true, planet.get(), set_mouse_properties);}><Icon icon={i::AiCaretLeftFilled} /></button>
-        <button class="arrow" id="arrow_right" on:click=move |_| {switch_view(false, planet.get(), set_mouse_properties);}><Icon icon={i::AiCaretRightFilled} /></button>
+        <nav id="view_tabs" aria-label=move || translate(language.get(), "Views")>
+            {VIEWS.iter().enumerate().map(|(index, (_, _, label))| {
+                let label = *label;
+                view! {
+                    <A href={view_href(index)} exact=true>{move || translate(language.get(), label)}</A>
+                }
+            }).collect_view()}
+        </nav>
+        <button class="arrow" id="arrow_left" aria-label=move || translate(language.get(), "Previous view") on:click=move |_| {go_to_left(true);}><Icon icon={i::AiCaretLeftFilled} /></button>
+        <button class="arrow" id="arrow_right" aria-label=move || translate(language.get(), "Next view") on:click=move |_| {go_to(false);}><Icon icon={i::AiCaretRightFilled} /></button>
     }
 }
\ No newline at end of file
@@ -0,0 +1,147 @@
+use leptos::prelude::*;
+use leptos_chartistry::*;
+use leptos_chartistry::IntoInner;
+use web_sys::js_sys::Date;
+
+use crate::utils::{conjunctions::find_orbital_events, heliocentric_position::calculate_heliocentric_position, julian_date::{calculate_days_since_j2000, format_j2000_date}, orbital_period::calculate_orbital_period, presets::{load_presets, PlanetPreset}, synodic_period::calculate_synodic_period, i18n::translate, units::{AU, DAYS_PER_YEAR}};
+use super::{planet::PlanetData, language::{translated_title, use_language}, unit_settings::use_unit_settings, chart_table::ChartTable};
+use super::switch_view::is_view_active;
+
+#[derive(Clone, PartialEq)]
+struct ChartData {
+    x: f64,
+    y: f64
+}
+
+impl ChartData {
+    fn add(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+fn get_period(preset: &PlanetPreset, planet: &PlanetData) -> f64 {
+    calculate_orbital_period(preset.a * AU, planet.g, preset.m, planet.m_sun, planet.m_earth)
+}
+
+fn get_position(preset: &PlanetPreset, period: f64, days: f64) -> (f64, f64) {
+    calculate_heliocentric_position(preset.a, preset.e, preset.longitude_perihelion, preset.mean_longitude, period, days)
+}
+
+fn get_chart_data(observer: &PlanetPreset, target: &PlanetPreset, planet: &PlanetData, today: f64, end: f64, step: f64) -> Vec<ChartData> {
+    let (observer_period, target_period) = (get_period(observer, planet), get_period(target, planet));
+    let mut data = Vec::new();
+    let mut days = 0.0;
+    while days < end {
+        let (x_observer, y_observer) = get_position(observer, observer_period, today + days);
+        let (x_target, y_target) = get_position(target, target_period, today + days);
+        data.push(ChartData::add(days, f64::hypot(x_target - x_observer, y_target - y_observer)));
+        days += step;
+    }
+    data
+}
+
+#[component]
+fn BodySelect(label: &'static str, selected: (ReadSignal<usize>, WriteSignal<usize>)) -> impl IntoView {
+    view! {
+        <div class="input_select input">
+            <span class="input_select_label">{label}</span>
+            <select on:change=move |ev| {
+                selected.1.set(event_target_value(&ev).parse::<usize>().unwrap_or(0));
+            }>
+                {load_presets().iter().enumerate().map(|(index, preset)| {
+                    view! {
+                        <option value={index.to_string()} selected={index == selected.0.get_untracked()}>{preset.name.clone()}</option>
+                    }
+                }).collect_view()}
+            </select>
+        </div>
+    }
+}
+
+//Synodic period, upcoming alignments and distance over time between two presets
+#[component]
+pub fn SynodicComparison(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    // Default to Earth and Mars
+    let (observer, set_observer) = signal(2_usize);
+    let (target, set_target) = signal(3_usize);
+    let today = calculate_days_since_j2000(Date::now());
+    let units = use_unit_settings();
+    let language = use_language();
+
+    let bodies = move || {
+        let presets = load_presets();
+        (presets[observer.get()].clone(), presets[target.get()].clone())
+    };
+
+    let synodic_period = Memo::new(move |_| {
+        let (observer, target) = bodies();
+        calculate_synodic_period(get_period(&observer, &planet.get()), get_period(&target, &planet.get()))
+    });
+
+    // Look two synodic periods ahead, but at most a century
+    let window = move || (synodic_period.get() * 2.0 * DAYS_PER_YEAR).min(100.0 * DAYS_PER_YEAR);
+
+    let events = Memo::new(move |_| {
+        let (observer, target) = bodies();
+        let (observer_period, target_period) = (get_period(&observer, &planet.get()), get_period(&target, &planet.get()));
+        find_orbital_events(
+            |days| get_position(&observer, observer_period, days),
+            |days| get_position(&target, target_period, days),
+            target.a < observer.a,
+            today,
+            today + window(),
+            window() / 4000.0,
+        )
+    });
+
+    let chart_data = Memo::new(move |_| {
+        let (observer, target) = bodies();
+        get_chart_data(&observer, &target, &planet.get(), today, window(), window() / 500.0)
+    });
+
+    let series = Series::new(|data: &ChartData| data.x)
+        .line(Line::new(|data: &ChartData| data.y).with_name("Distance (AU):"))
+        .with_min_y(0.0);
+
+    let active = is_view_active("synodic_comparison");
+    view! {
+        <div id="synodic_comparison" class="tool_panel view" class:invisible_element=move || !active.get()>
+            <div class="input_section">
+                <BodySelect label="Observer: " selected=(observer, set_observer) />
+                <BodySelect label="Target: " selected=(target, set_target) />
+            </div>
+            <Show when=move || synodic_period.get().is_finite()
+                fallback=|| view!{<div class="small_property">"Select two different objects"</div>}>
+                <div class="small_property">
+                    <span>{move || translate(language.get(), "Synodic Period: ")} {move || units.get().format_time(synodic_period.get(), language.get())}</span>
+                </div>
+                <ul class="event_list">
+                    {move || events.get().into_iter().take(12).map(|event| view! {
+                        <li>{format_j2000_date(event.days)} ": " {event.name}</li>
+                    }).collect_view()}
+                </ul>
+                <Chart
+                    aspect_ratio=AspectRatio::from_env()
+                    series=series.clone()
+                    data=chart_data
+                    top=translated_title("Distance between the objects")
+                    left=vec![RotatedLabel::end("Distance (AU)").into(), TickLabels::aligned_floats().into()]
+                    bottom=vec![TickLabels::aligned_floats().into(), RotatedLabel::end("Days from today").into()]
+                    inner=[
+                        AxisMarker::left_edge().into_inner(),
+                        AxisMarker::bottom_edge().into_inner(),
+                        XGridLine::default().into_inner(),
+                        YGridLine::default().into_inner(),
+                        YGuideLine::over_mouse().into_inner(),
+                        XGuideLine::over_data().into_inner(),
+                    ]
+                />
+                <ChartTable
+                    columns=Signal::derive(|| vec![String::from("Days from today"), String::from("Distance (AU)")])
+                    rows=Signal::derive(move || chart_data.get().iter().map(|data| vec![data.x, data.y]).collect())
+                />
+                <span class="note">"Orbits are treated as coplanar Keplerian ellipses"</span>
+            </Show>
+        </div>
+    }
+}
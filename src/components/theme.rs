@@ -0,0 +1,58 @@
+use leptos::prelude::*;
+use wasm_bindgen::{JsCast, closure::Closure};
+
+use crate::utils::{i18n::translate, theme::{load_theme, save_theme, Theme}};
+use super::language::use_language;
+
+const THEMES: [(Theme, &str); 5] = [
+    (Theme::System, "System"),
+    (Theme::Light, "Light"),
+    (Theme::Dark, "Dark"),
+    (Theme::HighContrast, "High contrast"),
+    (Theme::Print, "Print"),
+];
+
+// Share the theme with all components, store every change and set it on the document for the CSS
+pub fn provide_theme() {
+    let (theme, set_theme) = signal(load_theme());
+    Effect::new(move |_| {
+        save_theme(theme.get());
+        if let Some(root) = document().document_element() {
+            root.set_attribute("data-theme", theme.get().code()).unwrap();
+        }
+    });
+
+    // The system theme resolves differently once the preferred colour scheme changes, so everything using the theme updates
+    if let Ok(Some(query)) = window().match_media("(prefers-color-scheme: dark)") {
+        let on_change = Closure::<dyn Fn()>::new(move || {
+            if theme.get_untracked() == Theme::System {
+                set_theme.notify();
+            }
+        });
+        query.add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref()).unwrap();
+        on_change.forget();
+    }
+    provide_context((theme, set_theme));
+}
+
+pub fn use_theme() -> ReadSignal<Theme> {
+    expect_context::<(ReadSignal<Theme>, WriteSignal<Theme>)>().0
+}
+
+#[component]
+pub fn ThemeSelect() -> impl IntoView {
+    let (theme, set_theme) = expect_context::<(ReadSignal<Theme>, WriteSignal<Theme>)>();
+    let language = use_language();
+
+    view! {
+        <span class="input_select_label">{move || translate(language.get(), "Theme: ")}</span>
+        <select on:change=move |ev| set_theme.set(THEMES[event_target_value(&ev).parse::<usize>().unwrap_or(0)].0)>
+            {THEMES.iter().enumerate().map(|(index, (option, name))| {
+                let name = *name;
+                view! {
+                    <option value={index.to_string()} selected={*option == theme.get_untracked()}>{move || translate(language.get(), name)}</option>
+                }
+            }).collect_view()}
+        </select>
+    }
+}
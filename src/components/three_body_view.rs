@@ -0,0 +1,185 @@
+use leptos::prelude::*;
+use std::f64::consts::PI;
+use wasm_bindgen::JsCast;
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+
+use crate::utils::{lagrange_points::{calculate_jacobi_constant, calculate_lagrange_points, calculate_mass_ratio}, theme::CanvasPalette, i18n::{fill_template, format_number, format_scientific, translate}};
+use super::{planet::PlanetData, theme::use_theme, language::use_language};
+use super::switch_view::is_view_active;
+
+// Resolution of the canvas in pixels, it is scaled to the panel by CSS
+const CANVAS_SIZE: f64 = 600.0;
+// Size of a sampled cell of the zero-velocity regions in pixels
+const CELL_SIZE: f64 = 3.0;
+
+// Visible part of the rotating frame as (center x, center y, half width)
+#[derive(Clone, Copy)]
+struct View {
+    center: (f64, f64),
+    half_width: f64,
+}
+
+impl View {
+    fn to_canvas(self, x: f64, y: f64) -> (f64, f64) {
+        let scale = CANVAS_SIZE / 2.0 / self.half_width;
+        (CANVAS_SIZE / 2.0 + (x - self.center.0) * scale, CANVAS_SIZE / 2.0 - (y - self.center.1) * scale)
+    }
+
+    fn to_frame(self, canvas_x: f64, canvas_y: f64) -> (f64, f64) {
+        let scale = CANVAS_SIZE / 2.0 / self.half_width;
+        (self.center.0 + (canvas_x - CANVAS_SIZE / 2.0) / scale, self.center.1 - (canvas_y - CANVAS_SIZE / 2.0) / scale)
+    }
+}
+
+// Jacobi constants at the Lagrange points, the boundaries between the topologies of the zero-velocity curves
+fn get_lagrange_constants(mu: f64) -> [f64; 5] {
+    calculate_lagrange_points(mu).map(|(x, y)| calculate_jacobi_constant(x, y, mu))
+}
+
+// Range of Jacobi constants offered by the energy slider, from above L1 to below L4
+fn get_jacobi_range(mu: f64) -> (f64, f64) {
+    let constants = get_lagrange_constants(mu);
+    let spread = constants[0] - constants[3];
+    (constants[3] - 0.1 * spread, constants[0] + 0.5 * spread)
+}
+
+fn draw_zero_velocity_curves(ctx: &CanvasRenderingContext2d, mu: f64, jacobi_constant: f64, view: View) {
+    let cells = (CANVAS_SIZE / CELL_SIZE) as usize;
+
+    // A particle with this Jacobi constant can not enter regions where it would exceed the effective potential
+    let forbidden: Vec<bool> = (0..cells * cells).map(|index| {
+        let (x, y) = view.to_frame(((index % cells) as f64 + 0.5) * CELL_SIZE, ((index / cells) as f64 + 0.5) * CELL_SIZE);
+        calculate_jacobi_constant(x, y, mu) < jacobi_constant
+    }).collect();
+
+    for row in 0..cells {
+        for column in 0..cells {
+            if !forbidden[row * cells + column] {
+                continue;
+            }
+
+            let on_curve = [(0, 1), (2, 1), (1, 0), (1, 2)].iter().any(|(dx, dy)| {
+                let (neighbour_column, neighbour_row) = ((column + dx).wrapping_sub(1), (row + dy).wrapping_sub(1));
+                neighbour_column < cells && neighbour_row < cells && !forbidden[neighbour_row * cells + neighbour_column]
+            });
+
+            ctx.set_fill_style_str(if on_curve { "tomato" } else { "rgba(255, 99, 71, 0.3)" });
+            ctx.fill_rect(column as f64 * CELL_SIZE, row as f64 * CELL_SIZE, CELL_SIZE, CELL_SIZE);
+        }
+    }
+}
+
+fn draw_bodies(ctx: &CanvasRenderingContext2d, mu: f64, view: View, palette: CanvasPalette) {
+    let (star_x, star_y) = view.to_canvas(-mu, 0.0);
+    let (object_x, object_y) = view.to_canvas(1.0 - mu, 0.0);
+
+    ctx.set_fill_style_str(palette.star);
+    ctx.begin_path();
+    ctx.arc(star_x, star_y, 8.0, 0.0, 2.0 * PI).expect("Failed to draw arc");
+    ctx.fill();
+    ctx.set_fill_style_str(palette.object);
+    ctx.begin_path();
+    ctx.arc(object_x, object_y, 5.0, 0.0, 2.0 * PI).expect("Failed to draw arc");
+    ctx.fill();
+
+    ctx.set_fill_style_str(palette.lagrange_points);
+    ctx.set_font("16px Arial");
+    ctx.set_text_align("center");
+    for (index, (x, y)) in calculate_lagrange_points(mu).iter().enumerate() {
+        let (canvas_x, canvas_y) = view.to_canvas(*x, *y);
+        ctx.begin_path();
+        ctx.arc(canvas_x, canvas_y, 3.0, 0.0, 2.0 * PI).expect("Failed to draw arc");
+        ctx.fill();
+        ctx.fill_text(&format!("L{}", index + 1), canvas_x, canvas_y - 8.0).expect("Failed to write text");
+    }
+}
+
+fn draw_three_body_view(mu: f64, jacobi_constant: f64, view: View, palette: CanvasPalette) {
+    let Some(canvas) = document().get_element_by_id("three_body_canvas") else {
+        return;
+    };
+    let canvas = canvas.dyn_into::<HtmlCanvasElement>().unwrap();
+    let ctx = canvas
+        .get_context("2d")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<CanvasRenderingContext2d>()
+        .unwrap();
+
+    ctx.clear_rect(0.0, 0.0, CANVAS_SIZE, CANVAS_SIZE);
+    draw_zero_velocity_curves(&ctx, mu, jacobi_constant, view);
+    draw_bodies(&ctx, mu, view, palette);
+}
+
+//Zero-velocity curves of a test particle in the frame rotating with the object around its star
+#[component]
+pub fn ThreeBodyView(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    // Slider position from 0 (lowest energy) to 1000, starts between L2 and L3 where the horseshoe appears
+    let (energy, set_energy) = signal(None::<f64>);
+    let (zoom, set_zoom) = signal(1.0);
+    let theme = use_theme();
+    let language = use_language();
+
+    let mu = move || calculate_mass_ratio(planet.get().m_object.0.get() * planet.get().m_earth, planet.get().m_sun);
+    let jacobi_constant = move || {
+        let (low, high) = get_jacobi_range(mu());
+        match energy.get() {
+            Some(energy) => high - (high - low) * energy / 1000.0,
+            None => {
+                let constants = get_lagrange_constants(mu());
+                (constants[1] + constants[2]) / 2.0
+            }
+        }
+    };
+    let view = move || {
+        if zoom.get() > 1.0 {
+            View { center: (1.0 - mu(), 0.0), half_width: 1.5 / zoom.get() }
+        } else {
+            View { center: (0.0, 0.0), half_width: 1.5 }
+        }
+    };
+
+    Effect::new(move |_| {
+        if planet.get().m_object.0.get() != 0.0 {
+            draw_three_body_view(mu(), jacobi_constant(), view(), theme.get().palette());
+        }
+    });
+
+    let active = is_view_active("three_body_view");
+    view! {
+        <div id="three_body_view" class="tool_panel view" class:invisible_element=move || !active.get()>
+            <span>{move || translate(language.get(), "Rotating frame of the restricted three-body problem")}</span>
+            <Show when=move || planet.get().m_object.0.get() != 0.0
+                fallback=move || view!{<div class="small_property">{move || translate(language.get(), "Mass is undefined")}</div>}>
+                <canvas id="three_body_canvas" width=CANVAS_SIZE height=CANVAS_SIZE/>
+                <label>{move || translate(language.get(), "Test particle energy ")}
+                    <input type="range" min="0" max="1000" step="1" prop:value=move || {
+                        let (low, high) = get_jacobi_range(mu());
+                        energy.get().unwrap_or((high - jacobi_constant()) / (high - low) * 1000.0)
+                    } on:input=move |ev| {
+                        set_energy.set(event_target_value(&ev).parse::<f64>().ok());
+                    } />
+                </label>
+                <label>{move || translate(language.get(), "Zoom on the object ")}
+                    <select on:change=move |ev| set_zoom.set(event_target_value(&ev).parse::<f64>().unwrap_or(1.0))>
+                        <option value="1">"1×"</option>
+                        <option value="10">"10×"</option>
+                        <option value="100">"100×"</option>
+                        <option value="1000">"1000×"</option>
+                    </select>
+                </label>
+                <span>{move || fill_template(
+                    translate(language.get(), "Jacobi constant C = {}, energy per mass E = {} (μ = {})"),
+                    &[format_number(language.get(), jacobi_constant(), 7), format_number(language.get(), -jacobi_constant() / 2.0, 7), format_scientific(language.get(), mu(), 3)],
+                )}</span>
+                <span class="note">{move || {
+                    let constants = get_lagrange_constants(mu());
+                    fill_template(
+                        translate(language.get(), "C at L1 {}, L2 {}, L3 {}, L4/L5 {}"),
+                        &constants[..4].iter().map(|constant| format_number(language.get(), *constant, 7)).collect::<Vec<_>>(),
+                    )
+                }}</span>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,114 @@
+use leptos::prelude::*;
+use leptos_chartistry::*;
+use leptos_chartistry::IntoInner;
+use std::f64::consts::PI;
+
+use crate::utils::{kepler::{calculate_mean_anomaly, calculate_true_anomaly}, orbital_period::calculate_orbital_period, radius::calculate_radius, transit::{calculate_impact_parameter, calculate_transit_flux, LIMB_DARKENING}, units::DAYS_PER_YEAR};
+use super::{planet::PlanetData, language::translated_title, chart_table::ChartTable};
+use super::switch_view::is_view_active;
+
+#[derive(Clone, PartialEq)]
+struct ChartData {
+    x: f64,
+    y: f64
+}
+
+impl ChartData {
+    fn add(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+// Estimate the full transit duration in hours
+fn get_transit_duration(planet: &PlanetData, period_hours: f64) -> f64 {
+    let (a, e) = (planet.a.0.get(), planet.e.0.get());
+    let (inclination, argument_periapsis) = (planet.inclination.0.get().to_radians(), planet.argument_periapsis.0.get().to_radians());
+    let p = planet.d.0.get() * planet.d_earth * 1000.0 / 2.0 / planet.r_sun;
+    let b = calculate_impact_parameter(a, e, inclination, argument_periapsis, planet.r_sun);
+
+    let chord = f64::sqrt(((1.0 + p).powi(2) - b.powi(2)).max(0.0)) * planet.r_sun / a / inclination.sin();
+    period_hours / PI * chord.min(1.0).asin() * f64::sqrt(1.0 - e * e) / (1.0 + e * argument_periapsis.sin())
+}
+
+fn get_chart_data(planet: &PlanetData, start: f64, end: f64, step: f64) -> Vec<ChartData> {
+    let (a, e) = (planet.a.0.get(), planet.e.0.get());
+    let (inclination, argument_periapsis) = (planet.inclination.0.get().to_radians(), planet.argument_periapsis.0.get().to_radians());
+    let p = planet.d.0.get() * planet.d_earth * 1000.0 / 2.0 / planet.r_sun;
+    let period_hours = calculate_orbital_period(a, planet.g, planet.m_object.0.get(), planet.m_sun, planet.m_earth) * DAYS_PER_YEAR * 24.0;
+
+    // The object is in front of the star when the angle from the ascending node is 90°
+    let transit_mean_anomaly = calculate_mean_anomaly(PI / 2.0 - argument_periapsis, e);
+
+    let mut data = Vec::new();
+    let mut time = start;
+    while time < end {
+        let angle = calculate_true_anomaly(transit_mean_anomaly + 2.0 * PI * time / period_hours, e);
+        let radius = calculate_radius(a, e, angle);
+        let x = radius * (angle + argument_periapsis).cos();
+        let y = radius * (angle + argument_periapsis).sin() * inclination.cos();
+        let towards_observer = (angle + argument_periapsis).sin() * inclination.sin() > 0.0;
+
+        let flux = if towards_observer {
+            calculate_transit_flux(f64::hypot(x, y) / planet.r_sun, p, LIMB_DARKENING)
+        } else {
+            1.0
+        };
+        data.push(ChartData::add(time, flux));
+        time += step;
+    }
+    data
+}
+
+#[component]
+pub fn TransitLightCurveChart(planet: ReadSignal<PlanetData>) -> impl IntoView {
+    let duration = Memo::new(move |_| {
+        let planet = planet.get();
+        let period_hours = calculate_orbital_period(planet.a.0.get(), planet.g, planet.m_object.0.get(), planet.m_sun, planet.m_earth) * DAYS_PER_YEAR * 24.0;
+        get_transit_duration(&planet, period_hours)
+    });
+
+    let active = is_view_active("transit_light_curve_chart");
+    let chart_data = Memo::new(move |previous: Option<&Vec<ChartData>>| {
+        // The hidden view keeps its curve instead of recomputing it on every change
+        if !active.get() {
+            return previous.cloned().unwrap_or_default();
+        }
+        // Show the transit and the same time again on both sides
+        let half_window = 1.5 * duration.get();
+        get_chart_data(&planet.get(), -half_window, half_window, half_window / 200.0)
+    });
+
+    let series = Series::new(|data: &ChartData| data.x)
+        .line(Line::new(|data: &ChartData| data.y).with_name("Relative Flux:"))
+        .with_max_y(1.0);
+
+    view! {
+        <div id="transit_light_curve_chart" class="view" class:invisible_element=move || !active.get()>
+            <Show when=move || { duration.get() > 0.0 }
+                fallback=move || view!{<div class="small_property">{move || format!("No transit at this inclination (impact parameter {:.2})",
+                    calculate_impact_parameter(planet.get().a.0.get(), planet.get().e.0.get(), planet.get().inclination.0.get().to_radians(), planet.get().argument_periapsis.0.get().to_radians(), planet.get().r_sun))}</div>}>
+                <Chart
+                    aspect_ratio=AspectRatio::from_env()
+                    series=series.clone()
+                    data=chart_data
+                    top=translated_title("Transit Light Curve")
+                    left=vec![RotatedLabel::end("Relative Flux").into(), TickLabels::default().with_format(|value: &f64, _| format!("{:.5}", value)).into()]
+                    bottom=vec![TickLabels::aligned_floats().into(), RotatedLabel::end("Hours from mid-transit").into()]
+                    inner=vec![
+                        AxisMarker::left_edge().into_inner(),
+                        AxisMarker::bottom_edge().into_inner(),
+                        XGridLine::default().into_inner(),
+                        YGridLine::default().into_inner(),
+                        YGuideLine::over_mouse().into_inner(),
+                        XGuideLine::over_data().into_inner(),
+                    ]
+                />
+                <ChartTable
+                    columns=Signal::derive(|| vec![String::from("Hours from mid-transit"), String::from("Relative Flux")])
+                    rows=Signal::derive(move || chart_data.get().iter().map(|data| vec![data.x, data.y]).collect())
+                />
+                <span class="note">{move || format!("Transit duration {:.2} h, quadratic limb darkening", duration.get())}</span>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,69 @@
+use leptos::prelude::*;
+
+use crate::utils::{i18n::{translate, Language}, units::{load_unit_settings, save_unit_settings, AngleUnit, DistanceUnit, TimeUnit, UnitSettings, UnitSystem}};
+use super::language::use_language;
+
+const SYSTEMS: [(UnitSystem, &str); 3] = [
+    (UnitSystem::Si, "SI (km/s, m/s², N, km)"),
+    (UnitSystem::Astronomical, "Astronomical (AU/day, g, N, km)"),
+    (UnitSystem::Imperial, "Imperial (mi/s, ft/s², lbf, mi)"),
+];
+const ANGLES: [(AngleUnit, &str); 2] = [(AngleUnit::Radians, "Radians"), (AngleUnit::Degrees, "Degrees")];
+const DISTANCES: [(DistanceUnit, &str); 3] = [
+    (DistanceUnit::AstronomicalUnits, "AU"),
+    (DistanceUnit::Kilometers, "km"),
+    (DistanceUnit::LightMinutes, "Light-minutes"),
+];
+const TIMES: [(TimeUnit, &str); 2] = [(TimeUnit::Days, "Days"), (TimeUnit::Years, "Years")];
+
+// Share the unit settings with all components and store every change
+pub fn provide_unit_settings() {
+    let (units, set_units) = signal(load_unit_settings());
+    Effect::new(move |_| save_unit_settings(&units.get()));
+    provide_context((units, set_units));
+}
+
+pub fn use_unit_settings() -> ReadSignal<UnitSettings> {
+    expect_context::<(ReadSignal<UnitSettings>, WriteSignal<UnitSettings>)>().0
+}
+
+fn unit_options<T: PartialEq + Copy>(units: &[(T, &'static str)], selected: T, language: ReadSignal<Language>) -> impl IntoView {
+    units.iter().enumerate().map(|(index, (unit, name))| {
+        let name = *name;
+        view! {
+            <option value={index.to_string()} selected={*unit == selected}>{move || translate(language.get(), name)}</option>
+        }
+    }).collect_view()
+}
+
+//Selects for the units used by all displays and inputs
+#[component]
+pub fn UnitSettingsSelect() -> impl IntoView {
+    let (units, set_units) = expect_context::<(ReadSignal<UnitSettings>, WriteSignal<UnitSettings>)>();
+    let selected = move |ev: &leptos::ev::Event| event_target_value(ev).parse::<usize>().unwrap_or(0);
+    let current = units.get_untracked();
+    let language = use_language();
+
+    view! {
+        <div class="input_section">
+            <div class="input_select input input_half">
+                <span class="input_select_label">{move || translate(language.get(), "Units: ")}</span>
+                <select on:change=move |ev| set_units.update(|units| units.system = SYSTEMS[selected(&ev)].0)>
+                    {unit_options(&SYSTEMS, current.system, language)}
+                </select>
+                <select on:change=move |ev| set_units.update(|units| units.angle = ANGLES[selected(&ev)].0)>
+                    {unit_options(&ANGLES, current.angle, language)}
+                </select>
+            </div>
+            <div class="input_select input input_half">
+                <span class="input_select_label">{move || translate(language.get(), "Distances and periods: ")}</span>
+                <select on:change=move |ev| set_units.update(|units| units.distance = DISTANCES[selected(&ev)].0)>
+                    {unit_options(&DISTANCES, current.distance, language)}
+                </select>
+                <select on:change=move |ev| set_units.update(|units| units.time = TIMES[selected(&ev)].0)>
+                    {unit_options(&TIMES, current.time, language)}
+                </select>
+            </div>
+        </div>
+    }
+}
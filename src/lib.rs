@@ -19,7 +19,7 @@ pub fn App() -> impl IntoView {
     provide_meta_context();
 
     view! {
-        <Html attr:lang="en" attr:dir="ltr" attr:data-theme="light"/>
+        <Html attr:dir="ltr"/>
 
         // sets the document title
         <Title text="Planet Properties Calculator"/>
@@ -30,7 +30,7 @@ pub fn App() -> impl IntoView {
 
         <Router>
             <Routes fallback=|| view!{ NotFound }>
-                <Route path=path!("/") view=|| view!{ <Home/> }/>
+                <Route path=path!("/:view?") view=|| view!{ <Home/> }/>
                 <Route path=path!("/*any") view=|| view!{ <NotFound/> }/>
             </Routes>
         </Router>
@@ -1,15 +1,27 @@
 //use crate::components::calculations::ExampleSedna;
 use leptos::prelude::*;
 
-use crate::components::{planet::{Inputs, create_planet}, orbital_velocity_chart::VelocityChart, orbit_visualization::OrbitVisualization, small_properties::SmallProperties, gravitational_force_with_sun_chart::GravitationalForceWithSunChart, orbit_visualization_options::OrbitVisualizationOptions, switch_view::SwitchView};
+use crate::utils::presets::load_presets;
+use crate::components::{planet::{Inputs, create_planet}, orbital_velocity_chart::VelocityChart, orbit_visualization::OrbitVisualization, small_properties::SmallProperties, gravitational_force_with_sun_chart::GravitationalForceWithSunChart, orbit_visualization_options::{OrbitVisualizationOptions, create_visualization_options}, switch_view::SwitchView, synodic_comparison::SynodicComparison, transit_light_curve_chart::TransitLightCurveChart, radial_velocity_chart::RadialVelocityChart, precession::Precession, three_body_view::ThreeBodyView, flyby::Flyby, lift_energy_chart::LiftEnergyChart, launch_delta_v::LaunchDeltaV, delta_v_budget::DeltaVBudgetBuilder, gravity_profile_chart::GravityProfileChart, chart_builder::ChartBuilder, comparison_table::ComparisonTable, unit_settings::provide_unit_settings, language::provide_language, theme::provide_theme, switch_view::{provide_active_view, use_active_view}};
+use crate::pages::not_found::NotFound;
 
 /// Default Home Page
 #[component]
 pub fn Home() -> impl IntoView {
-    //Default values for Sedna
-    let (planet, update_planet) = signal(create_planet(1.0, 0.017, 1.0, 1.0));
+    //Default values for Earth
+    let (planet, update_planet) = signal(create_planet(&load_presets()[2]));
     // (is_hovering, angle, radius, velocity)
     let (mouse_properties, set_mouse_properties) = signal((false, 0.0, 0.0, 0.0, 0.0));
+    // Unit settings, the language and the theme are read by the components from the context
+    provide_unit_settings();
+    provide_language();
+    provide_theme();
+    // The view is selected by the path, like /velocity
+    provide_active_view();
+    let active_view = use_active_view();
+    let visualization_options = create_visualization_options();
+    // (payload mass in kg, altitude in km)
+    let (lift_parameters, set_lift_parameters) = signal((1000.0, 750.0));
 
     view! {
         <ErrorBoundary fallback=|errors| {
@@ -31,16 +43,29 @@ pub fn Home() -> impl IntoView {
             }
         }>
 
+        <Show when=move || active_view.get().is_some() fallback=|| view! { <NotFound/> }>
+        <SwitchView />
         <div class="container">
-            <OrbitVisualization planet={planet} set_mouse_properties={set_mouse_properties} mouse_properties={mouse_properties}/>
-            <VelocityChart planet={planet} />
-            <SmallProperties planet={planet} />
+            <OrbitVisualization planet={planet} options={visualization_options} set_mouse_properties={set_mouse_properties} mouse_properties={mouse_properties}/>
+            <VelocityChart planet={planet} comparison={visualization_options.comparison.0} />
+            <SmallProperties planet={planet} lift_parameters={lift_parameters} set_lift_parameters={set_lift_parameters} comparison={visualization_options.comparison.0} />
             <Inputs planet_signal=(planet, update_planet)/>
-            <GravitationalForceWithSunChart planet={planet} />
-            <OrbitVisualizationOptions planet={planet} />
-            <SwitchView planet={planet} set_mouse_properties={set_mouse_properties} />
-            <div id="mobile_support">"For now, use a bigger device"</div>
+            <GravitationalForceWithSunChart planet={planet} comparison={visualization_options.comparison.0} />
+            <OrbitVisualizationOptions planet={planet} options={visualization_options} />
+            <SynodicComparison planet={planet} />
+            <TransitLightCurveChart planet={planet} />
+            <RadialVelocityChart planet={planet} />
+            <Precession planet={planet} />
+            <ThreeBodyView planet={planet} />
+            <Flyby planet={planet} options={visualization_options} />
+            <LiftEnergyChart planet={planet} lift_parameters={lift_parameters} />
+            <LaunchDeltaV planet={planet} />
+            <DeltaVBudgetBuilder planet={planet} />
+            <GravityProfileChart planet={planet} />
+            <ChartBuilder planet={planet} />
+            <ComparisonTable planet={planet} lift_parameters={lift_parameters} />
         </div>
+        </Show>
         </ErrorBoundary>
     }
 }
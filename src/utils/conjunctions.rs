@@ -0,0 +1,83 @@
+use std::f64::consts::PI;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct OrbitalEvent {
+    // Days after J2000
+    pub days: f64,
+    pub name: String,
+}
+
+impl OrbitalEvent {
+    fn new(days: f64, name: &str) -> Self {
+        Self { days, name: String::from(name) }
+    }
+}
+
+// Angle of the target as seen from the observer, measured from the direction to the star (positive to the east)
+pub fn calculate_elongation(observer: (f64, f64), target: (f64, f64)) -> f64 {
+    let to_star = (-observer.0, -observer.1);
+    let to_target = (target.0 - observer.0, target.1 - observer.1);
+
+    f64::atan2(to_star.0 * to_target.1 - to_star.1 * to_target.0, to_star.0 * to_target.0 + to_star.1 * to_target.1)
+}
+
+// Difference of the heliocentric longitudes of target and observer in (-PI, PI]
+fn longitude_difference(observer: (f64, f64), target: (f64, f64)) -> f64 {
+    let difference = target.1.atan2(target.0) - observer.1.atan2(observer.0);
+    (difference + PI).rem_euclid(2.0 * PI) - PI
+}
+
+// Find conjunctions, oppositions and greatest elongations of a target seen from an observer between two dates
+pub fn find_orbital_events(
+    position_observer: impl Fn(f64) -> (f64, f64),
+    position_target: impl Fn(f64) -> (f64, f64),
+    target_is_inner: bool,
+    start: f64,
+    end: f64,
+    step: f64,
+) -> Vec<OrbitalEvent> {
+    let difference = |days: f64| longitude_difference(position_observer(days), position_target(days));
+    let elongation = |days: f64| calculate_elongation(position_observer(days), position_target(days)).abs();
+
+    let mut events = Vec::new();
+    let mut days = start;
+    while days < end {
+        let next = days + step;
+        let (before, after) = (difference(days), difference(next));
+
+        // The longitudes line up whenever the sine of their difference changes sign
+        if before.sin().signum() != after.sin().signum() {
+            let (mut low, mut high) = (days, next);
+            for _ in 0..40 {
+                let middle = (low + high) / 2.0;
+                if difference(middle).sin().signum() == before.sin().signum() {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+
+            let aligned = difference(low).cos() > 0.0;
+            let name = match (target_is_inner, aligned) {
+                (true, true) => "Inferior conjunction",
+                (true, false) => "Superior conjunction",
+                (false, true) => "Opposition",
+                (false, false) => "Conjunction",
+            };
+            events.push(OrbitalEvent::new(low, name));
+        }
+
+        // Inner objects additionally have a maximum angular distance from the star
+        if target_is_inner && elongation(days) < elongation(next) && elongation(next) > elongation(next + step) {
+            let name = if calculate_elongation(position_observer(next), position_target(next)) > 0.0 {
+                "Greatest eastern elongation"
+            } else {
+                "Greatest western elongation"
+            };
+            events.push(OrbitalEvent::new(next, name));
+        }
+
+        days = next;
+    }
+    events
+}
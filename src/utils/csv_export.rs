@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{js_sys::Array, Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+// Quote a CSV field if it contains a separator, quote or line break
+pub fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// Let the browser download a CSV file with the given rows
+pub fn download_csv(file_name: &str, rows: &[Vec<String>]) {
+    let content = rows
+        .iter()
+        .map(|row| row.iter().map(|field| escape_csv_field(field)).collect::<Vec<_>>().join(","))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let options = BlobPropertyBag::new();
+    options.set_type("text/csv");
+    let Ok(blob) = Blob::new_with_str_sequence_and_options(&Array::of1(&JsValue::from_str(&content)), &options) else {
+        return;
+    };
+    let Ok(url) = Url::create_object_url_with_blob(&blob) else {
+        return;
+    };
+
+    if let Some(link) = leptos::prelude::document().create_element("a").ok().and_then(|element| element.dyn_into::<HtmlAnchorElement>().ok()) {
+        link.set_href(&url);
+        link.set_download(file_name);
+        link.click();
+    }
+    // Browsers may still be reading the blob right after the click
+    leptos::prelude::set_timeout(move || {
+        Url::revoke_object_url(&url).ok();
+    }, Duration::from_secs(1));
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use web_sys::window;
+use log::info;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Stage {
+    // Masses in kg
+    pub dry_mass: f64,
+    pub propellant_mass: f64,
+    // Specific impulse in s
+    pub isp: f64,
+}
+
+impl Default for Stage {
+    fn default() -> Self {
+        Self { dry_mass: 4000.0, propellant_mass: 40000.0, isp: 350.0 }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum ManeuverKind {
+    Launch,
+    Transfer,
+    Capture,
+    Landing,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Maneuver {
+    pub kind: ManeuverKind,
+    // Δv in km/s
+    pub delta_v: f64,
+    // Index of the stage that performs the maneuver
+    pub stage: usize,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DeltaVBudget {
+    // Payload above the last stage in kg
+    pub payload_mass: f64,
+    // Stages in the order they are burned
+    pub stages: Vec<Stage>,
+    pub maneuvers: Vec<Maneuver>,
+}
+
+impl Default for DeltaVBudget {
+    fn default() -> Self {
+        Self { payload_mass: 1000.0, stages: vec![Stage::default()], maneuvers: vec![] }
+    }
+}
+
+pub fn save_delta_v_budget(budget: &DeltaVBudget) {
+    if let Some(win) = window() {
+        if let Ok(Some(storage)) = win.local_storage() {
+            storage.set_item("delta_v_budget", &serde_json::to_string(budget).unwrap()).unwrap();
+        }
+    }
+}
+
+pub fn load_delta_v_budget() -> DeltaVBudget {
+    if let Some(win) = window() {
+        if let Ok(Some(storage)) = win.local_storage() {
+            if let Ok(Some(stored_data)) = storage.get_item("delta_v_budget") {
+                match serde_json::from_str::<DeltaVBudget>(&stored_data) {
+                    Ok(budget) => return budget,
+                    Err(e) => info!("Error deserializing data: {}", e),
+                }
+            }
+        }
+    }
+    DeltaVBudget::default()
+}
\ No newline at end of file
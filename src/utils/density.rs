@@ -0,0 +1,8 @@
+use std::f64::consts::PI;
+
+// Calculate the mean density of a celestial object in kg/m³
+pub fn calculate_density(d: f64, d_earth: f64, m: f64, m_earth: f64) -> f64 {
+    let radius_m = (d * d_earth * 1000.0) / 2.0;
+
+    m * m_earth / (4.0 / 3.0 * PI * radius_m.powi(3))
+}
\ No newline at end of file
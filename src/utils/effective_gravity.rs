@@ -0,0 +1,16 @@
+use std::f64::consts::PI;
+
+use crate::utils::gravitational_acceleration::calculate_gravitational_acceleration;
+
+// Calculate the gravitational acceleration at the equator reduced by the centrifugal acceleration of the rotation
+pub fn calculate_equatorial_gravity(d: f64, d_earth: f64, m: f64, m_earth: f64, rotation_period: f64) -> f64 {
+    let radius_m = (d * d_earth * 1000.0) / 2.0;
+    let angular_velocity = 2.0 * PI / (rotation_period * 60.0 * 60.0);
+
+    calculate_gravitational_acceleration(d, m, m_earth) - angular_velocity.powi(2) * radius_m
+}
+
+// Calculate the gravitational acceleration at the poles, where the rotation has no centrifugal effect
+pub fn calculate_polar_gravity(d: f64, m: f64, m_earth: f64) -> f64 {
+    calculate_gravitational_acceleration(d, m, m_earth)
+}
@@ -0,0 +1,99 @@
+use crate::utils::{presets::{PlanetPreset, StarPreset}, units::DAYS_PER_YEAR};
+
+// Split a CSV line into fields, honouring double-quoted fields that contain commas
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            },
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+// Estimate the radius in Earth radii from the mass in Earth masses with the power laws of Chen & Kipping (2017)
+fn estimate_radius(m: f64) -> f64 {
+    if m < 2.04 {
+        m.powf(0.279)
+    } else if m < 132.0 {
+        1.008 * 2.04_f64.powf(0.279 - 0.589) * m.powf(0.589)
+    } else {
+        // Jovian worlds barely change in size with mass
+        17.7 * m.powf(-0.044)
+    }
+}
+
+// Convert the rows of a NASA Exoplanet Archive "Planetary Systems" CSV export into presets
+pub fn parse_exoplanet_archive_csv(text: &str) -> Result<Vec<PlanetPreset>, String> {
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'));
+    let header = split_csv_line(lines.next().ok_or("The file is empty")?);
+
+    let column = |name: &str| header.iter().position(|column| column.trim() == name);
+    let name_column = column("pl_name").ok_or("Missing column pl_name")?;
+
+    let mut presets: Vec<PlanetPreset> = Vec::new();
+    for line in lines {
+        let fields = split_csv_line(line);
+        let value = |name: &str| column(name)
+            .and_then(|index| fields.get(index))
+            .and_then(|field| field.trim().parse::<f64>().ok());
+
+        // The full table lists one row per publication, only keep the default parameter set
+        if value("default_flag").is_some_and(|flag| flag != 1.0) {
+            continue;
+        }
+
+        let name = fields.get(name_column).map(|name| name.trim().to_string()).unwrap_or_default();
+        if name.is_empty() || presets.iter().any(|preset| preset.name == name) {
+            continue;
+        }
+
+        let star_r = value("st_rad").unwrap_or(1.0);
+        let star_t_eff = value("st_teff").unwrap_or(5772.0);
+        let star = StarPreset {
+            name: column("hostname").and_then(|index| fields.get(index)).cloned().unwrap_or_default(),
+            m: value("st_mass").unwrap_or(1.0),
+            r: star_r,
+            // The archive lists log10 of the luminosity, fall back to the Stefan-Boltzmann law
+            l: value("st_lum").map(|log_l| 10.0_f64.powf(log_l)).unwrap_or(star_r.powi(2) * (star_t_eff / 5772.0).powi(4)),
+            t_eff: star_t_eff,
+        };
+
+        // Derive the semi-major axis from the period with Kepler's third law if it is missing
+        let a = match (value("pl_orbsmax"), value("pl_orbper")) {
+            (Some(a), _) => a,
+            (None, Some(period_days)) => f64::cbrt(star.m * (period_days / DAYS_PER_YEAR).powi(2)),
+            (None, None) => continue,
+        };
+        let m = value("pl_bmasse").unwrap_or(0.0);
+        let d = value("pl_rade").unwrap_or_else(|| if m > 0.0 { estimate_radius(m) } else { 1.0 });
+
+        presets.push(PlanetPreset {
+            name,
+            m,
+            d,
+            a,
+            e: value("pl_orbeccen").unwrap_or(0.0).clamp(0.0, 0.99),
+            inclination: value("pl_orbincl").unwrap_or(90.0),
+            argument_periapsis: value("pl_orblper").unwrap_or(90.0),
+            star,
+            ..Default::default()
+        });
+    }
+
+    if presets.is_empty() {
+        return Err(String::from("No planets with a semi-major axis or period found"));
+    }
+    Ok(presets)
+}
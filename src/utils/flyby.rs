@@ -0,0 +1,25 @@
+use std::f64::consts::PI;
+
+use crate::utils::state_vector::{elements_from_state_vector, state_vector_from_elements, Frame, KeplerianElements, StateVector};
+
+// Calculate the angle by which a hyperbolic flyby turns the velocity relative to the object
+pub fn calculate_turning_angle(v_infinity: f64, r_periapsis: f64, m_object_kg: f64, g: f64) -> f64 {
+    2.0 * f64::asin(1.0 / (1.0 + r_periapsis * v_infinity.powi(2) / (g * m_object_kg)))
+}
+
+// Calculate the velocity of an object at a point of its orbit as (x, y) in the orbital plane, x points to the periapsis
+pub fn calculate_velocity_vector(a: f64, e: f64, m_sun: f64, g: f64, angle: f64) -> (f64, f64) {
+    let elements = KeplerianElements { p: a * (1.0 - e * e), e, inclination: 0.0, longitude_ascending_node: 0.0, argument_periapsis: 0.0, true_anomaly: angle };
+    let velocity = state_vector_from_elements(&elements, g * m_sun, Frame::Perifocal).velocity;
+    (velocity[0], velocity[1])
+}
+
+// Calculate (semi-latus rectum, eccentricity, angle of the periapsis) of the orbit through a position with a velocity,
+// the semi-latus rectum stays finite for parabolic orbits
+pub fn calculate_orbit_from_state(position: (f64, f64), velocity: (f64, f64), m_sun: f64, g: f64) -> (f64, f64, f64) {
+    let state = StateVector { position: [position.0, position.1, 0.0], velocity: [velocity.0, velocity.1, 0.0], frame: Frame::Reference };
+    let elements = elements_from_state_vector(&state, g * m_sun);
+    // ω is measured in the direction of motion, which is clockwise for retrograde orbits
+    let periapsis_angle = if elements.inclination > PI / 2.0 { -elements.argument_periapsis } else { elements.argument_periapsis };
+    (elements.p, elements.e, periapsis_angle)
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+// Gravitational field of the object at a distance r in m from its center
+
+// Calculate the gravitational acceleration in m/s²
+pub fn calculate_acceleration_at_distance(r: f64, m_object_kg: f64, g: f64) -> f64 {
+    g * m_object_kg / r.powi(2)
+}
+
+// Calculate the gravitational potential in J/kg
+pub fn calculate_potential_at_distance(r: f64, m_object_kg: f64, g: f64) -> f64 {
+    -g * m_object_kg / r
+}
+
+// Calculate the escape velocity in m/s
+pub fn calculate_escape_velocity_at_distance(r: f64, m_object_kg: f64, g: f64) -> f64 {
+    f64::sqrt(2.0 * g * m_object_kg / r)
+}
\ No newline at end of file
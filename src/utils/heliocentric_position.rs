@@ -0,0 +1,13 @@
+use std::f64::consts::PI;
+
+use crate::utils::{kepler::calculate_true_anomaly, radius::calculate_radius, units::DAYS_PER_YEAR};
+
+// Calculate the position (x, y) in the orbital plane, in the unit of a, a number of days after J2000
+pub fn calculate_heliocentric_position(a: f64, e: f64, longitude_perihelion: f64, mean_longitude: f64, period_years: f64, days: f64) -> (f64, f64) {
+    let mean_anomaly = (mean_longitude - longitude_perihelion).to_radians() + 2.0 * PI * days / (period_years * DAYS_PER_YEAR);
+    let true_anomaly = calculate_true_anomaly(mean_anomaly, e);
+    let radius = calculate_radius(a, e, true_anomaly);
+    let longitude = true_anomaly + longitude_perihelion.to_radians();
+
+    (radius * longitude.cos(), radius * longitude.sin())
+}
@@ -0,0 +1,4 @@
+// Calculate the Hill radius at the periapsis, the distance up to which the object dominates the gravity of its star
+pub fn calculate_hill_radius(a: f64, e: f64, m_object_kg: f64, m_sun: f64) -> f64 {
+    a * (1.0 - e) * f64::cbrt(m_object_kg / (3.0 * m_sun))
+}
\ No newline at end of file
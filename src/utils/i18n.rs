@@ -0,0 +1,353 @@
+use serde::{Deserialize, Serialize};
+use web_sys::window;
+use log::info;
+
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    // Code for the lang attribute of the document
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+        }
+    }
+
+    // (decimal separator, thousands separator)
+    pub fn separators(&self) -> (char, char) {
+        match self {
+            Language::English => ('.', ','),
+            Language::German => (',', '.'),
+        }
+    }
+}
+
+// German catalogue, the English text is the key and used if no translation exists
+const GERMAN: [(&str, &str); 215] = [
+    // Inputs
+    ("Presets: ", "Vorlagen: "),
+    ("Custom", "Benutzerdefiniert"),
+    ("Solar System", "Sonnensystem"),
+    ("Exoplanets", "Exoplaneten"),
+    ("Imported", "Importiert"),
+    ("Host Star: ", "Zentralstern: "),
+    ("NASA Exoplanet Archive CSV: ", "CSV des NASA Exoplanet Archive: "),
+    ("Clear imported", "Importierte entfernen"),
+    ("Semi-Major Axis", "Große Halbachse"),
+    ("Orbit Eccentricity", "Bahnexzentrizität"),
+    ("Object Mass", "Masse des Objekts"),
+    ("Object Diameter", "Durchmesser des Objekts"),
+    ("Undefined", "Unbekannt"),
+    ("Rotation Period", "Rotationsperiode"),
+    ("Axial Tilt", "Achsneigung"),
+    ("Inclination to the Sky", "Inklination zur Himmelsebene"),
+    ("Argument of Periapsis", "Argument der Periapsis"),
+    ("Language: ", "Sprache: "),
+    ("Units: ", "Einheiten: "),
+    ("Theme: ", "Farbschema: "),
+    ("System", "System"),
+    ("Light", "Hell"),
+    ("Dark", "Dunkel"),
+    ("High contrast", "Hoher Kontrast"),
+    ("Print", "Druck"),
+    ("Distances and periods: ", "Entfernungen und Perioden: "),
+    ("SI (km/s, m/s², N, km)", "SI (km/s, m/s², N, km)"),
+    ("Astronomical (AU/day, g, N, km)", "Astronomisch (AE/Tag, g, N, km)"),
+    ("Imperial (mi/s, ft/s², lbf, mi)", "Angloamerikanisch (mi/s, ft/s², lbf, mi)"),
+    ("Radians", "Bogenmaß"),
+    ("Degrees", "Grad"),
+    ("Light-minutes", "Lichtminuten"),
+    ("Days", "Tage"),
+    ("Years", "Jahre"),
+    // View tabs
+    ("Views", "Ansichten"),
+    ("Previous view", "Vorherige Ansicht"),
+    ("Next view", "Nächste Ansicht"),
+    ("Orbit", "Umlaufbahn"),
+    ("Radial velocity", "Radialgeschwindigkeit"),
+    ("Lift energy", "Hubenergie"),
+    ("Gravity profile", "Schwerkraftprofil"),
+    ("Chart builder", "Diagrammbaukasten"),
+    ("Properties", "Eigenschaften"),
+    ("Comparison", "Vergleich"),
+    ("Synodic periods", "Synodische Perioden"),
+    ("Precession", "Präzession"),
+    ("Three bodies", "Drei Körper"),
+    ("Flyby", "Vorbeiflug"),
+    ("Launch Δv", "Start-Δv"),
+    ("Δv budget", "Δv-Budget"),
+    ("Orbit options", "Bahnoptionen"),
+    // Units
+    ("AU", "AE"),
+    ("light-minutes", "Lichtminuten"),
+    ("days", "Tage"),
+    ("years", "Jahre"),
+    ("AU/day", "AE/Tag"),
+    ("Earth masses", "Erdmassen"),
+    ("Earth diameters", "Erddurchmesser"),
+    // Properties
+    ("Escape Velocity: ", "Fluchtgeschwindigkeit: "),
+    ("Gravitational Acceleration: ", "Fallbeschleunigung: "),
+    ("Payload mass (kg): ", "Masse der Nutzlast (kg): "),
+    ("Altitude", "Höhe"),
+    ("Work required to lift the payload from the surface to the altitude: ", "Arbeit, um die Nutzlast von der Oberfläche auf die Höhe zu heben: "),
+    ("Energy to reach a circular orbit at the altitude: ", "Energie für eine Kreisbahn in der Höhe: "),
+    (" J (ideal Δv ", " J (ideales Δv "),
+    ("Orbital Period: ", "Umlaufzeit: "),
+    ("Sidereal Day: ", "Siderischer Tag: "),
+    ("Solar Day: none (tidally locked)", "Sonnentag: keiner (gebundene Rotation)"),
+    ("Solar Day: ", "Sonnentag: "),
+    (" days)", " Tage)"),
+    ("Axial Tilt: ", "Achsneigung: "),
+    (" (retrograde rotation)", " (rückläufige Rotation)"),
+    ("Synchronous Orbit: below the surface", "Synchrone Umlaufbahn: unter der Oberfläche"),
+    ("Synchronous Orbit: ", "Synchrone Umlaufbahn: "),
+    (" km radius, ", " km Radius, "),
+    (" km altitude", " km Höhe"),
+    ("Effective Gravity: ", "Effektive Schwerkraft: "),
+    (" at the equator, ", " am Äquator, "),
+    (" at the poles", " an den Polen"),
+    ("Stellar Flux: ", "Strahlungsfluss: "),
+    ("Equilibrium Temperature: ", "Gleichgewichtstemperatur: "),
+    (" K (zero albedo)", " K (Albedo null)"),
+    ("Property", "Eigenschaft"),
+    ("Current object", "Aktuelles Objekt"),
+    ("Ratio", "Verhältnis"),
+    ("Mass", "Masse"),
+    ("Diameter", "Durchmesser"),
+    ("Surface gravity", "Schwerkraft an der Oberfläche"),
+    ("Escape velocity", "Fluchtgeschwindigkeit"),
+    ("Density", "Dichte"),
+    ("Semi-major axis", "Große Halbachse"),
+    ("Orbital period", "Umlaufzeit"),
+    ("Some properties are missing, due to the mass being unknown.", "Einige Eigenschaften fehlen, da die Masse unbekannt ist."),
+    ("Rotation properties are missing, due to the rotation period being unknown.", "Eigenschaften der Rotation fehlen, da die Rotationsperiode unbekannt ist."),
+    // Hover text of the orbit
+    ("At angle {} and radius {} the velocity is {}", "Bei Winkel {} und Radius {} beträgt die Geschwindigkeit {}"),
+    ("At angle {} and radius {} the velocity is {} and the gravitational force with the star is {}", "Bei Winkel {} und Radius {} beträgt die Geschwindigkeit {} und die Gravitationskraft mit dem Stern {}"),
+    ("Orbit visualization, the arrow keys move a probe along the orbit", "Bahnvisualisierung, die Pfeiltasten bewegen eine Sonde entlang der Bahn"),
+    // Charts
+    ("Data table", "Datentabelle"),
+    ("Orbital Velocity", "Bahngeschwindigkeit"),
+    ("Gravitational Force with Sun", "Gravitationskraft mit der Sonne"),
+    ("Radial Velocity of the Star", "Radialgeschwindigkeit des Sterns"),
+    ("Distance between the objects", "Abstand zwischen den Objekten"),
+    ("Chart Builder", "Diagrammbaukasten"),
+    ("Transit Light Curve", "Transit-Lichtkurve"),
+    ("Energy to lift a Payload and to reach a circular Orbit", "Energie zum Heben einer Nutzlast und für eine Kreisbahn"),
+    ("Gravity Profile", "Schwerkraftprofil"),
+    ("Speed", "Geschwindigkeit"),
+    ("Velocity", "Geschwindigkeit"),
+    ("Radial", "Radial"),
+    ("Tangential", "Tangential"),
+    ("Flight path angle", "Bahnneigungswinkel"),
+    ("True anomaly", "Wahre Anomalie"),
+    ("Distance from the star", "Abstand zum Stern"),
+    ("Speed, radial and tangential velocity", "Geschwindigkeit mit radialer und tangentialer Komponente"),
+    ("Force", "Kraft"),
+    ("Not a number", "Keine Zahl"),
+    ("Unknown unit {}", "Unbekannte Einheit {}"),
+    ("The value has to be at least {}", "Der Wert muss mindestens {} sein"),
+    ("The value has to be between {} and {}", "Der Wert muss zwischen {} und {} liegen"),
+    ("Semi-major axis and eccentricity", "Große Halbachse und Exzentrizität"),
+    ("Periapsis and apoapsis", "Periapsis und Apoapsis"),
+    ("Period and eccentricity", "Umlaufzeit und Exzentrizität"),
+    ("Periapsis distance q and eccentricity", "Periapsisdistanz q und Exzentrizität"),
+    ("State vector", "Zustandsvektor"),
+    ("Periapsis distance", "Periapsisdistanz"),
+    ("Apoapsis distance", "Apoapsisdistanz"),
+    ("Orbit defined by: ", "Bahn festgelegt durch: "),
+    ("Apply", "Übernehmen"),
+    ("These values describe no closed orbit around the star", "Diese Werte beschreiben keine geschlossene Bahn um den Stern"),
+    ("Speed at periapsis: ", "Geschwindigkeit in der Periapsis: "),
+    ("at apoapsis: ", "in der Apoapsis: "),
+    ("State vector at this point ({}): r = ({}) {}, v = ({}) {}", "Zustandsvektor an diesem Punkt ({}): r = ({}) {}, v = ({}) {}"),
+    ("sky plane", "Himmelsebene"),
+    ("perifocal", "perifokal"),
+    ("Synodic Period: ", "Synodische Periode: "),
+    // Comparison table
+    ("Name", "Name"),
+    ("Group", "Gruppe"),
+    ("Filter by name", "Nach Name filtern"),
+    ("All groups", "Alle Gruppen"),
+    ("Export CSV", "CSV exportieren"),
+    ("Period", "Umlaufzeit"),
+    ("Periapsis", "Periapsis"),
+    ("Apoapsis", "Apoapsis"),
+    ("Periapsis speed", "Geschwindigkeit in der Periapsis"),
+    ("Apoapsis speed", "Geschwindigkeit in der Apoapsis"),
+    ("Lift energy for {} kg to {} {}, set in the properties", "Hubenergie für {} kg auf {} {}, einstellbar in den Eigenschaften"),
+    // Launch and Δv budget
+    ("Mass or diameter is undefined", "Masse oder Durchmesser ist unbekannt"),
+    ("Launch from the surface to a low orbit", "Start von der Oberfläche in eine niedrige Umlaufbahn"),
+    ("Orbit altitude", "Bahnhöhe"),
+    ("Thrust-to-weight ratio: ", "Schub-Gewichts-Verhältnis: "),
+    ("Launch site latitude (°): ", "Breite des Startplatzes (°): "),
+    ("Include atmospheric drag ", "Luftwiderstand einbeziehen "),
+    ("Surface pressure (bar): ", "Luftdruck an der Oberfläche (bar): "),
+    ("Circular orbit velocity: ", "Kreisbahngeschwindigkeit: "),
+    ("Ideal Δv including the climb: ", "Ideales Δv einschließlich des Aufstiegs: "),
+    ("Gravity loss: {}, drag loss: {}", "Gravitationsverlust: {}, Luftwiderstandsverlust: {}"),
+    ("Rotation bonus for an eastward launch: {}", "Rotationsgewinn bei einem Start nach Osten: {}"),
+    ("Rotation bonus is missing, due to the rotation period being unknown", "Der Rotationsgewinn fehlt, da die Rotationsperiode unbekannt ist"),
+    ("Total Δv to orbit: ", "Gesamtes Δv bis zur Umlaufbahn: "),
+    ("Specific impulse (s): ", "Spezifischer Impuls (s): "),
+    ("Stages: ", "Stufen: "),
+    ("Dry mass per stage mass without payload: ", "Trockenmasse pro Stufenmasse ohne Nutzlast: "),
+    ("Propellant mass fraction: ", "Treibstoffanteil der Masse: "),
+    ("Payload fraction with {} stages: {} %", "Nutzlastanteil mit {} Stufen: {} %"),
+    ("Orbit not reachable with {} stages of this dry mass", "Umlaufbahn mit {} Stufen dieser Trockenmasse nicht erreichbar"),
+    ("Gravity and drag losses are rough estimates scaled from typical launches on Earth", "Gravitations- und Luftwiderstandsverluste sind grobe Schätzungen, skaliert von typischen Starts auf der Erde"),
+    ("Launch", "Start"),
+    ("Transfer", "Transfer"),
+    ("Capture", "Einfang"),
+    ("Landing", "Landung"),
+    ("Stage {}", "Stufe {}"),
+    ("Dry mass (kg): ", "Trockenmasse (kg): "),
+    ("Propellant mass (kg): ", "Treibstoffmasse (kg): "),
+    ("Δv {}, margin {}", "Δv {}, Reserve {}"),
+    ("Δv {}, short by {}", "Δv {}, es fehlen {}"),
+    ("Remove stage", "Stufe entfernen"),
+    ("Add stage", "Stufe hinzufügen"),
+    ("Performed by ", "Ausgeführt von "),
+    ("Remove maneuver", "Manöver entfernen"),
+    ("Add a maneuver at the current object: ", "Manöver am aktuellen Objekt hinzufügen: "),
+    ("Required {} of {} available, total margin {}", "Benötigt {} von {} verfügbar, gesamte Reserve {}"),
+    ("Launch uses the escape velocity, landing the orbital velocity at the surface without atmosphere, transfer and capture a Hohmann transfer between periapsis and apoapsis of the current orbit", "Der Start nutzt die Fluchtgeschwindigkeit, die Landung die Bahngeschwindigkeit an der Oberfläche ohne Atmosphäre, Transfer und Einfang einen Hohmann-Transfer zwischen Periapsis und Apoapsis der aktuellen Bahn"),
+    // Flyby
+    ("Gravity assist flyby", "Swing-by-Manöver"),
+    ("Excess velocity v∞", "Überschussgeschwindigkeit v∞"),
+    ("Periapsis altitude", "Höhe der Periapsis"),
+    ("Approach direction from the object's velocity", "Anflugrichtung zur Geschwindigkeit des Objekts"),
+    ("Object's angle from periapsis", "Winkel des Objekts von der Periapsis"),
+    ("Pass behind the object ", "Hinter dem Objekt vorbeifliegen "),
+    ("Show on the orbit visualization ", "In der Bahnvisualisierung zeigen "),
+    ("Turning angle: ", "Ablenkwinkel: "),
+    ("Outgoing v∞: {} at {} from the object's velocity", "Ausgehendes v∞: {} bei {} zur Geschwindigkeit des Objekts"),
+    ("Velocity around the star: {} before, {} after ({} from the object's velocity)", "Geschwindigkeit um den Stern: {} davor, {} danach ({} zur Geschwindigkeit des Objekts)"),
+    ("Speed gained: {} (Δv {})", "Geschwindigkeitsgewinn: {} (Δv {})"),
+    ("Orbit before: ", "Bahn davor: "),
+    ("Orbit after: ", "Bahn danach: "),
+    ("e = {}, escapes the star", "e = {}, verlässt den Stern"),
+    ("Planar patched conics, the flyby itself takes no time", "Ebene Patched Conics, der Vorbeiflug selbst dauert keine Zeit"),
+    // Precession
+    ("Relativistic Perihelion Advance: ", "Relativistische Periheldrehung: "),
+    ("{}″ per century ({}″ per orbit)", "{}″ pro Jahrhundert ({}″ pro Umlauf)"),
+    ("Satellite around the object", "Satellit um das Objekt"),
+    ("Eccentricity: ", "Exzentrizität: "),
+    ("Inclination", "Inklination"),
+    ("The oblateness (J2) of this object is unknown.", "Die Abplattung (J2) dieses Objekts ist unbekannt."),
+    ("Nodal Precession", "Knotenpräzession"),
+    ("Apsidal Precession", "Apsidenpräzession"),
+    ("day", "Tag"),
+    ("Sun-synchronous at an inclination of {}", "Sonnensynchron bei einer Inklination von {}"),
+    ("No sun-synchronous orbit at this altitude", "Keine sonnensynchrone Umlaufbahn in dieser Höhe"),
+    ("Satellite precession is missing, due to the mass being unknown.", "Die Präzession von Satelliten fehlt, da die Masse unbekannt ist."),
+    // Three bodies
+    ("Rotating frame of the restricted three-body problem", "Rotierendes Bezugssystem des eingeschränkten Dreikörperproblems"),
+    ("Mass is undefined", "Masse ist unbekannt"),
+    ("Test particle energy ", "Energie des Testteilchens "),
+    ("Zoom on the object ", "Vergrößerung auf das Objekt "),
+    ("Jacobi constant C = {}, energy per mass E = {} (μ = {})", "Jacobi-Konstante C = {}, Energie pro Masse E = {} (μ = {})"),
+    ("C at L1 {}, L2 {}, L3 {}, L4/L5 {}", "C bei L1 {}, L2 {}, L3 {}, L4/L5 {}"),
+];
+
+// Text in the language, the English text is returned if there is no translation
+pub fn translate(language: Language, text: &'static str) -> &'static str {
+    match language {
+        Language::English => text,
+        Language::German => GERMAN.iter().find(|(english, _)| *english == text).map(|(_, german)| *german).unwrap_or(text),
+    }
+}
+
+// Replace the {} of a translated template in order
+pub fn fill_template(template: &str, values: &[String]) -> String {
+    let mut parts = template.split("{}");
+    let mut filled = parts.next().unwrap_or_default().to_string();
+    for (part, value) in parts.zip(values.iter()) {
+        filled.push_str(value);
+        filled.push_str(part);
+    }
+    filled
+}
+
+// Format a number with the decimal and thousands separators of the language
+pub fn format_number(language: Language, value: f64, decimals: usize) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+
+    let (decimal_separator, thousands_separator) = language.separators();
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+
+    let mut grouped = String::new();
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            grouped.push(thousands_separator);
+        }
+        grouped.push(digit);
+    }
+    if !fraction.is_empty() {
+        grouped.push(decimal_separator);
+        grouped.push_str(fraction);
+    }
+
+    // No sign if the value rounds to zero
+    if value < 0.0 && grouped.chars().any(|digit| digit.is_ascii_digit() && digit != '0') {
+        format!("-{}", grouped)
+    } else {
+        grouped
+    }
+}
+
+// Format a number as mantissa × 10 to the power of the exponent, like 1.23 × 10²³
+pub fn format_scientific(language: Language, value: f64, decimals: usize) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return format_number(language, value, decimals);
+    }
+
+    let formatted = format!("{:.*e}", decimals, value);
+    let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+    let superscript: String = exponent.chars().map(|character| match character {
+        '-' => '⁻',
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        _ => '⁹',
+    }).collect();
+    format!("{} × 10{}", format_number(language, mantissa.parse::<f64>().unwrap_or(0.0), decimals), superscript)
+}
+
+pub fn save_language(language: Language) {
+    if let Some(win) = window() {
+        if let Ok(Some(storage)) = win.local_storage() {
+            storage.set_item("language", &serde_json::to_string(&language).unwrap()).unwrap();
+        }
+    }
+}
+
+pub fn load_language() -> Language {
+    if let Some(win) = window() {
+        if let Ok(Some(storage)) = win.local_storage() {
+            if let Ok(Some(stored_data)) = storage.get_item("language") {
+                match serde_json::from_str::<Language>(&stored_data) {
+                    Ok(language) => return language,
+                    Err(e) => info!("Error deserializing data: {}", e),
+                }
+            }
+        }
+    }
+    Language::default()
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::utils::presets::PlanetPreset;
+use web_sys::window;
+use log::info;
+
+pub fn add_imported_presets(presets: &[PlanetPreset]) {
+    if let Some(win) = window() {
+        if let Ok(Some(storage)) = win.local_storage() {
+            let mut stored = load_imported_presets();
+            stored.retain(|stored_preset| !presets.iter().any(|preset| preset.name == stored_preset.name));
+            stored.extend_from_slice(presets);
+            storage.set_item("imported_presets", &serde_json::to_string(&stored).unwrap()).unwrap();
+        }
+    }
+}
+
+pub fn load_imported_presets() -> Vec<PlanetPreset> {
+    if let Some(win) = window() {
+        if let Ok(Some(storage)) = win.local_storage() {
+            if let Ok(Some(stored_data)) = storage.get_item("imported_presets") {
+                match serde_json::from_str::<Vec<PlanetPreset>>(&stored_data) {
+                    Ok(vec) => return vec,
+                    Err(e) => info!("Error deserializing data: {}", e),
+                }
+            }
+        }
+    }
+    vec![]
+}
+
+pub fn clear_imported_presets() {
+    if let Some(win) = window() {
+        if let Ok(Some(storage)) = win.local_storage() {
+            storage.remove_item("imported_presets").unwrap();
+        }
+    }
+}
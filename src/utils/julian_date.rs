@@ -0,0 +1,20 @@
+// Convert a unix timestamp in milliseconds to days since the J2000 epoch
+pub fn calculate_days_since_j2000(unix_ms: f64) -> f64 {
+    unix_ms / 86_400_000.0 + 2_440_587.5 - 2_451_545.0
+}
+
+// Format days since the J2000 epoch as a calendar date (YYYY-MM-DD)
+pub fn format_j2000_date(days: f64) -> String {
+    // Days since 1970-01-01, then Howard Hinnant's civil-from-days algorithm
+    let z = (days + 10_957.5).floor() as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
@@ -0,0 +1,37 @@
+use std::f64::consts::PI;
+
+// Solve Kepler's equation M = E - e * sin(E) for the eccentric anomaly with Newton's method
+pub fn calculate_eccentric_anomaly(mean_anomaly: f64, e: f64) -> f64 {
+    let mean_anomaly = mean_anomaly.rem_euclid(2.0 * PI);
+    // Starting at PI converges reliably for highly eccentric orbits
+    let mut eccentric_anomaly = if e > 0.8 { PI } else { mean_anomaly };
+
+    for _ in 0..50 {
+        let delta = (eccentric_anomaly - e * eccentric_anomaly.sin() - mean_anomaly) / (1.0 - e * eccentric_anomaly.cos());
+        eccentric_anomaly -= delta;
+        if delta.abs() < 1e-12 {
+            break;
+        }
+    }
+    eccentric_anomaly
+}
+
+// Calculate the true anomaly (angle from the periapsis) for a given mean anomaly
+pub fn calculate_true_anomaly(mean_anomaly: f64, e: f64) -> f64 {
+    let eccentric_anomaly = calculate_eccentric_anomaly(mean_anomaly, e);
+
+    2.0 * f64::atan2(
+        f64::sqrt(1.0 + e) * (eccentric_anomaly / 2.0).sin(),
+        f64::sqrt(1.0 - e) * (eccentric_anomaly / 2.0).cos(),
+    )
+}
+
+// Calculate the mean anomaly for a given true anomaly, the inverse of calculate_true_anomaly
+pub fn calculate_mean_anomaly(true_anomaly: f64, e: f64) -> f64 {
+    let eccentric_anomaly = 2.0 * f64::atan2(
+        f64::sqrt(1.0 - e) * (true_anomaly / 2.0).sin(),
+        f64::sqrt(1.0 + e) * (true_anomaly / 2.0).cos(),
+    );
+
+    eccentric_anomaly - e * eccentric_anomaly.sin()
+}
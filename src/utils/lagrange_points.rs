@@ -0,0 +1,52 @@
+// Positions in the rotating frame of the circular restricted three-body problem: the separation is 1,
+// the barycenter is at the origin, the star at (-mu, 0) and the object at (1 - mu, 0)
+
+// Calculate the mass ratio mu = m / (M + m) of the object and its star
+pub fn calculate_mass_ratio(m_object_kg: f64, m_sun: f64) -> f64 {
+    m_object_kg / (m_sun + m_object_kg)
+}
+
+// Calculate the effective (gravitational plus centrifugal) potential in the rotating frame
+pub fn calculate_effective_potential(x: f64, y: f64, mu: f64) -> f64 {
+    let r_star = f64::hypot(x + mu, y);
+    let r_object = f64::hypot(x - 1.0 + mu, y);
+
+    (x * x + y * y) / 2.0 + (1.0 - mu) / r_star + mu / r_object
+}
+
+// Calculate the Jacobi constant of a test particle at rest in the rotating frame, the highest it can have at that point
+pub fn calculate_jacobi_constant(x: f64, y: f64, mu: f64) -> f64 {
+    2.0 * calculate_effective_potential(x, y, mu)
+}
+
+// Force along the line through star and object, zero at the collinear points
+fn collinear_force(x: f64, mu: f64) -> f64 {
+    x - (1.0 - mu) * (x + mu) / (x + mu).abs().powi(3) - mu * (x - 1.0 + mu) / (x - 1.0 + mu).abs().powi(3)
+}
+
+// Find the root of the collinear force between two positions with bisection
+fn find_collinear_point(mut low: f64, mut high: f64, mu: f64) -> f64 {
+    for _ in 0..200 {
+        let middle = (low + high) / 2.0;
+        if collinear_force(low, mu).signum() == collinear_force(middle, mu).signum() {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) / 2.0
+}
+
+// Calculate the positions of L1 to L5
+pub fn calculate_lagrange_points(mu: f64) -> [(f64, f64); 5] {
+    // Stay clear of the singularities at both bodies
+    let margin = 1e-12;
+
+    [
+        (find_collinear_point(-mu + margin, 1.0 - mu - margin, mu), 0.0),
+        (find_collinear_point(1.0 - mu + margin, 2.0, mu), 0.0),
+        (find_collinear_point(-2.0, -mu - margin, mu), 0.0),
+        (0.5 - mu, f64::sqrt(3.0) / 2.0),
+        (0.5 - mu, -f64::sqrt(3.0) / 2.0),
+    ]
+}
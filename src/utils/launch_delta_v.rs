@@ -0,0 +1,27 @@
+use std::f64::consts::PI;
+
+// Surface pressure and drag loss of a typical launch from Earth, used to scale the drag loss to other atmospheres
+const EARTH_SURFACE_PRESSURE: f64 = 101_325.0;
+const EARTH_DRAG_LOSS: f64 = 150.0;
+
+// Calculate the velocity of a circular orbit with the radius r in m/s
+pub fn calculate_circular_velocity(r: f64, m_object_kg: f64, g: f64) -> f64 {
+    f64::sqrt(g * m_object_kg / r)
+}
+
+// Estimate the gravity loss of an ascent in m/s: the burn to orbital velocity takes v / (n * g), during which gravity
+// acts against the flight path with an average sine of about 0.25 for a gravity turn
+pub fn calculate_gravity_loss(v_circular: f64, thrust_to_weight: f64) -> f64 {
+    0.25 * v_circular / thrust_to_weight
+}
+
+// Estimate the drag loss in m/s, scaled from Earth with the mass of the atmospheric column (pressure / gravity)
+pub fn calculate_drag_loss(surface_pressure: f64, surface_gravity: f64) -> f64 {
+    EARTH_DRAG_LOSS * (surface_pressure / surface_gravity) / (EARTH_SURFACE_PRESSURE / 9.80665)
+}
+
+// Calculate the velocity gained from the rotation of the object when launching eastwards at a latitude
+pub fn calculate_rotation_bonus(d: f64, d_earth: f64, rotation_period: f64, latitude: f64) -> f64 {
+    let radius_m = (d * d_earth * 1000.0) / 2.0;
+    2.0 * PI * radius_m / (rotation_period * 60.0 * 60.0) * latitude.cos()
+}
\ No newline at end of file
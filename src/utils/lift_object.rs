@@ -1,12 +1,23 @@
-//Calculate how much energy is needed to lift a 1000kg object 750km high
-pub fn calculate_lift_energy(d_ratio_earth: f64, d_earth: f64, m_planet_ratio_earth: f64, m_earth: f64, g: f64) -> f64 {
+//Calculate how much energy is needed to lift an object from the surface to an altitude in m
+pub fn calculate_lift_energy(d_ratio_earth: f64, d_earth: f64, m_planet_ratio_earth: f64, m_earth: f64, g: f64, m_object: f64, height: f64) -> f64 {
     let d = d_ratio_earth * d_earth * 1000.0;
     let m_planet = m_planet_ratio_earth * m_earth;
-    let m_object = 1000.0;
-    let height = 750000.0;
 
     let r_initial = d / 2.0;
     let r_final = r_initial + height;
 
     g * m_object * m_planet * (1.0 / r_initial - 1.0 / r_final)
+}
+
+//Calculate how much energy is needed to bring an object from the surface into a circular orbit at an altitude in m
+pub fn calculate_orbit_insertion_energy(d_ratio_earth: f64, d_earth: f64, m_planet_ratio_earth: f64, m_earth: f64, g: f64, m_object: f64, height: f64) -> f64 {
+    let r_final = d_ratio_earth * d_earth * 1000.0 / 2.0 + height;
+    let kinetic_energy = g * m_object * m_planet_ratio_earth * m_earth / (2.0 * r_final);
+
+    calculate_lift_energy(d_ratio_earth, d_earth, m_planet_ratio_earth, m_earth, g, m_object, height) + kinetic_energy
+}
+
+//Calculate the ideal Δv in m/s that carries the orbit-insertion energy, without losses
+pub fn calculate_orbit_insertion_delta_v(d_ratio_earth: f64, d_earth: f64, m_planet_ratio_earth: f64, m_earth: f64, g: f64, height: f64) -> f64 {
+    f64::sqrt(2.0 * calculate_orbit_insertion_energy(d_ratio_earth, d_earth, m_planet_ratio_earth, m_earth, g, 1.0, height))
 }
\ No newline at end of file
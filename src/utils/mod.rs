@@ -6,4 +6,33 @@ pub mod escape_velocity;
 pub mod gravitational_force_sun;
 pub mod orbital_period;
 pub mod lift_object;
-pub mod orbits;
\ No newline at end of file
+pub mod orbits;
+pub mod solar_day;
+pub mod synchronous_orbit;
+pub mod effective_gravity;
+pub mod kepler;
+pub mod synodic_period;
+pub mod heliocentric_position;
+pub mod julian_date;
+pub mod conjunctions;
+pub mod exoplanet_archive;
+pub mod imported_presets;
+pub mod stellar_flux;
+pub mod transit;
+pub mod radial_velocity;
+pub mod precession;
+pub mod lagrange_points;
+pub mod flyby;
+pub mod launch_delta_v;
+pub mod rocket_equation;
+pub mod delta_v_budget;
+pub mod hill_radius;
+pub mod gravity_profile;
+pub mod density;
+pub mod csv_export;
+pub mod units;
+pub mod i18n;
+pub mod theme;
+pub mod quantity;
+pub mod orbit_parameters;
+pub mod state_vector;
\ No newline at end of file
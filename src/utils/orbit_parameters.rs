@@ -0,0 +1,43 @@
+use std::f64::consts::PI;
+
+use crate::utils::{state_vector::{elements_from_state_vector, Frame, StateVector}, units::{DAYS_PER_YEAR, SECONDS_PER_DAY}};
+
+// Calculate the periapsis distance q of an orbit
+pub fn calculate_periapsis(a: f64, e: f64) -> f64 {
+    a * (1.0 - e)
+}
+
+// Calculate the apoapsis distance Q of an orbit
+pub fn calculate_apoapsis(a: f64, e: f64) -> f64 {
+    a * (1.0 + e)
+}
+
+// Semi-major axis and eccentricity from the periapsis and apoapsis distances, in either order
+pub fn elements_from_apsides(periapsis: f64, apoapsis: f64) -> (f64, f64) {
+    let (q, big_q) = (periapsis.min(apoapsis), periapsis.max(apoapsis));
+    ((q + big_q) / 2.0, (big_q - q) / (big_q + q))
+}
+
+// Semi-major axis from the periapsis distance q and the eccentricity, how comet orbits are catalogued
+pub fn semi_major_axis_from_periapsis(periapsis: f64, e: f64) -> f64 {
+    periapsis / (1.0 - e)
+}
+
+// Semi-major axis from the orbital period in years, the inverse of calculate_orbital_period
+pub fn semi_major_axis_from_period(period: f64, g: f64, m_object_earth_ratio: f64, m_sun: f64, m_earth: f64) -> f64 {
+    let period_seconds = period * DAYS_PER_YEAR * SECONDS_PER_DAY;
+    (g * (m_object_earth_ratio * m_earth + m_sun) * (period_seconds / (2.0 * PI)).powi(2)).cbrt()
+}
+
+// Semi-major axis and eccentricity from the distance to the star (m), the speed (m/s) and the flight path angle (rad),
+// None if the object is not bound to the star
+pub fn elements_from_state(r: f64, speed: f64, flight_path_angle: f64, m_sun: f64, g: f64) -> Option<(f64, f64)> {
+    // The orbit lies in the reference plane with the object on the x axis
+    let state = StateVector {
+        position: [r, 0.0, 0.0],
+        velocity: [speed * flight_path_angle.sin(), speed * flight_path_angle.cos(), 0.0],
+        frame: Frame::Reference,
+    };
+    let elements = elements_from_state_vector(&state, g * m_sun);
+    (r > 0.0 && elements.e < 1.0).then_some((elements.a(), elements.e))
+}
\ No newline at end of file
@@ -3,4 +3,18 @@ use crate::utils::radius::calculate_radius;
 // Calculate the orbital velocity of a celestial object
 pub fn calculate_orbital_velocity(a: f64, e: f64, m_sun: f64, g_constant: f64, angle: f64) -> f64 {
     f64::sqrt(g_constant * m_sun * (2.0 / calculate_radius(a, e, angle) - (1.0 / a))).round() / 1000.0
+}
+
+// Calculate the velocity component along the line from the star in km/s, positive when moving away
+pub fn calculate_radial_velocity_component(a: f64, e: f64, m_sun: f64, g_constant: f64, angle: f64) -> f64 {
+    f64::sqrt(g_constant * m_sun / (a * (1.0 - e * e))) * e * angle.sin() / 1000.0
+}
+
+// Calculate the velocity component perpendicular to the line from the star in km/s
+pub fn calculate_tangential_velocity_component(a: f64, e: f64, m_sun: f64, g_constant: f64, angle: f64) -> f64 {
+    f64::sqrt(g_constant * m_sun / (a * (1.0 - e * e))) * (1.0 + e * angle.cos()) / 1000.0
+}
+// Calculate the flight path angle between the velocity and the local horizontal in degrees, positive when moving away
+pub fn calculate_flight_path_angle(e: f64, angle: f64) -> f64 {
+    f64::atan2(e * angle.sin(), 1.0 + e * angle.cos()).to_degrees()
 }
\ No newline at end of file
@@ -0,0 +1,48 @@
+use std::f64::consts::PI;
+
+use crate::utils::units::{DAYS_PER_YEAR, SECONDS_PER_DAY};
+
+// Speed of light in m/s
+const C: f64 = 299_792_458.0;
+
+// Calculate the general-relativistic advance of the periapsis in radians per orbit
+pub fn calculate_relativistic_precession(a: f64, e: f64, m_sun: f64, g: f64) -> f64 {
+    6.0 * PI * g * m_sun / (C.powi(2) * a * (1.0 - e * e))
+}
+
+// Convert a precession per orbit in radians to arcseconds per century
+pub fn convert_to_arcsec_per_century(precession: f64, period_years: f64) -> f64 {
+    precession.to_degrees() * 3600.0 * 100.0 / period_years
+}
+
+// Mean motion and the oblateness factor J2 * (R/p)² shared by the J2 precession rates
+fn j2_factors(j2: f64, r_object: f64, m_object_kg: f64, g: f64, a_satellite: f64, e_satellite: f64) -> (f64, f64) {
+    let mean_motion = f64::sqrt(g * m_object_kg / a_satellite.powi(3));
+    let semi_latus_rectum = a_satellite * (1.0 - e_satellite * e_satellite);
+    (mean_motion, j2 * (r_object / semi_latus_rectum).powi(2))
+}
+
+// Calculate the regression of the ascending node of a satellite caused by the oblateness of its planet in rad/s
+pub fn calculate_nodal_precession(j2: f64, r_object: f64, m_object_kg: f64, g: f64, a_satellite: f64, e_satellite: f64, inclination: f64) -> f64 {
+    let (mean_motion, oblateness) = j2_factors(j2, r_object, m_object_kg, g, a_satellite, e_satellite);
+    -1.5 * mean_motion * oblateness * inclination.cos()
+}
+
+// Calculate the rotation of the periapsis of a satellite caused by the oblateness of its planet in rad/s
+pub fn calculate_apsidal_precession(j2: f64, r_object: f64, m_object_kg: f64, g: f64, a_satellite: f64, e_satellite: f64, inclination: f64) -> f64 {
+    let (mean_motion, oblateness) = j2_factors(j2, r_object, m_object_kg, g, a_satellite, e_satellite);
+    0.75 * mean_motion * oblateness * (5.0 * inclination.cos().powi(2) - 1.0)
+}
+
+// Calculate the inclination at which the node follows the star once per orbit of the planet (sun-synchronous orbit)
+pub fn calculate_sun_synchronous_inclination(j2: f64, r_object: f64, m_object_kg: f64, g: f64, a_satellite: f64, e_satellite: f64, period_years: f64) -> Option<f64> {
+    let (mean_motion, oblateness) = j2_factors(j2, r_object, m_object_kg, g, a_satellite, e_satellite);
+    let required_rate = 2.0 * PI / (period_years * DAYS_PER_YEAR * SECONDS_PER_DAY);
+    let cos_inclination = -required_rate / (1.5 * mean_motion * oblateness);
+
+    if cos_inclination.abs() <= 1.0 {
+        Some(cos_inclination.acos())
+    } else {
+        None
+    }
+}
@@ -1,10 +1,54 @@
-#[derive(Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct StarPreset {
+    pub name: String,
+    // Mass, radius and luminosity in solar units
+    pub m: f64,
+    pub r: f64,
+    pub l: f64,
+    // Effective temperature in kelvin
+    pub t_eff: f64,
+}
+
+impl StarPreset {
+    pub fn sun() -> Self {
+        Self {
+            name: String::from("Sun"),
+            m: 1.0,
+            r: 1.0,
+            l: 1.0,
+            t_eff: 5772.0
+        }
+    }
+}
+
+impl Default for StarPreset {
+    fn default() -> Self {
+        Self::sun()
+    }
+}
+
+#[derive(Clone, Default, Deserialize, Serialize)]
 pub struct PlanetPreset {
     pub name: String,
     pub m: f64,
     pub d: f64,
     pub a: f64,
     pub e: f64,
+    // Sidereal rotation period in hours, 0 if unknown
+    pub rotation_period: f64,
+    // Obliquity in degrees, values above 90 mean retrograde rotation
+    pub axial_tilt: f64,
+    // Second zonal harmonic of the gravity field (oblateness), 0 if unknown
+    pub j2: f64,
+    // Longitude of periapsis and mean longitude at J2000 in degrees, approximate for the trans-Neptunian objects
+    pub longitude_perihelion: f64,
+    pub mean_longitude: f64,
+    // Viewing geometry for the transit and radial velocity charts in degrees, an inclination of 90 is edge-on
+    pub inclination: f64,
+    pub argument_periapsis: f64,
+    pub star: StarPreset,
 }
 
 
@@ -15,126 +59,337 @@ pub fn load_presets() -> Vec<PlanetPreset> {
             m: 0.0553,
             d: 0.383,
             a: 0.387,
-            e: 0.206
+            e: 0.206,
+            rotation_period: 1407.6,
+            axial_tilt: 0.034,
+            j2: 0.0000503,
+            longitude_perihelion: 77.456,
+            mean_longitude: 252.251,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Venus"),
             m: 0.0553,
             d: 0.949,
             a: 0.72,
-            e: 0.007
+            e: 0.007,
+            rotation_period: 5832.5,
+            axial_tilt: 177.36,
+            j2: 0.000004458,
+            longitude_perihelion: 131.533,
+            mean_longitude: 181.98,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Earth"),
             m: 1.0,
             d: 1.0,
             a: 1.00,
-            e: 0.017
+            e: 0.017,
+            rotation_period: 23.9345,
+            axial_tilt: 23.44,
+            j2: 0.00108263,
+            longitude_perihelion: 102.947,
+            mean_longitude: 100.464,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Mars"),
             m: 0.107,
             d: 0.532,
             a: 1.51,
-            e: 0.094
+            e: 0.094,
+            rotation_period: 24.6229,
+            axial_tilt: 25.19,
+            j2: 0.00196045,
+            longitude_perihelion: 336.041,
+            mean_longitude: 355.453,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Jupiter"),
             m: 317.8,
             d: 11.21,
             a: 5.19,
-            e: 0.049
+            e: 0.049,
+            rotation_period: 9.925,
+            axial_tilt: 3.13,
+            j2: 0.014736,
+            longitude_perihelion: 14.331,
+            mean_longitude: 34.404,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Saturn"),
             m: 95.2,
             d: 9.45,
             a: 9.54,
-            e: 0.052
+            e: 0.052,
+            rotation_period: 10.656,
+            axial_tilt: 26.73,
+            j2: 0.016298,
+            longitude_perihelion: 93.057,
+            mean_longitude: 49.944,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Uranus"),
             m: 14.5,
             d: 4.01,
             a: 19.18,
-            e: 0.047
+            e: 0.047,
+            rotation_period: 17.24,
+            axial_tilt: 97.77,
+            j2: 0.00334343,
+            longitude_perihelion: 173.005,
+            mean_longitude: 313.232,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Neptune"),
             m: 17.1,
             d: 3.88,
             a: 30.08,
-            e: 0.01
+            e: 0.01,
+            rotation_period: 16.11,
+            axial_tilt: 28.32,
+            j2: 0.003411,
+            longitude_perihelion: 48.124,
+            mean_longitude: 304.88,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Pluto"),
             m: 0.0022,
             d: 0.187,
             a: 39.08,
-            e: 0.244
+            e: 0.244,
+            rotation_period: 153.2928,
+            axial_tilt: 122.53,
+            j2: 0.0,
+            longitude_perihelion: 224.067,
+            mean_longitude: 238.929,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Haumea"),
             m: 0.00066,
             d: 0.125,
             a: 43.10,
-            e: 0.2
+            e: 0.2,
+            rotation_period: 3.9155,
+            axial_tilt: 0.0,
+            j2: 0.0,
+            longitude_perihelion: 1.1,
+            mean_longitude: 10.1,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Quaoar"),
             m: 0.0002,
             d: 0.0852,
             a: 43.33,
-            e: 0.04
+            e: 0.04,
+            rotation_period: 17.68,
+            axial_tilt: 0.0,
+            j2: 0.0,
+            longitude_perihelion: 336.4,
+            mean_longitude: 242.0,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Makemake"),
             m: 0.000519,
             d: 0.112,
             a: 45.50,
-            e: 0.16
+            e: 0.16,
+            rotation_period: 22.83,
+            axial_tilt: 0.0,
+            j2: 0.0,
+            longitude_perihelion: 14.4,
+            mean_longitude: 152.8,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Gonggong"),
             m: 0.000293,
             d: 0.0965,
             a: 67.07,
-            e: 0.5
+            e: 0.5,
+            rotation_period: 44.81,
+            axial_tilt: 0.0,
+            j2: 0.0,
+            longitude_perihelion: 184.1,
+            mean_longitude: 277.9,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Eris"),
             m: 0.0027,
             d: 0.183,
             a: 67.84,
-            e: 0.43
+            e: 0.43,
+            rotation_period: 378.87,
+            axial_tilt: 0.0,
+            j2: 0.0,
+            longitude_perihelion: 187.6,
+            mean_longitude: 21.5,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Sedna"),
             m: 0.0,
             d: 0.0781,
             a: 506.0,
-            e: 0.85
+            e: 0.85,
+            rotation_period: 10.27,
+            axial_tilt: 0.0,
+            j2: 0.0,
+            longitude_perihelion: 96.0,
+            mean_longitude: 93.6,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("Leleākūhonua"),
             m: 0.0,
             d: 0.0173,
             a: 1089.65,
-            e: 0.952
+            e: 0.952,
+            rotation_period: 0.0,
+            axial_tilt: 0.0,
+            j2: 0.0,
+            longitude_perihelion: 58.8,
+            mean_longitude: 59.8,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("2017 MB7"),
             m: 0.0,
             d: 0.0005,
             a: 1758.49,
-            e: 0.998
+            e: 0.998,
+            rotation_period: 0.0,
+            axial_tilt: 0.0,
+            j2: 0.0,
+            longitude_perihelion: 0.0,
+            mean_longitude: 0.0,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         },
         PlanetPreset {
             name: String::from("2014 FE72"),
             m: 0.0,
             d: 0.0212,
             a: 2044.34,
-            e: 0.983
+            e: 0.983,
+            rotation_period: 0.0,
+            axial_tilt: 0.0,
+            j2: 0.0,
+            longitude_perihelion: 0.0,
+            mean_longitude: 0.0,
+            inclination: 90.0,
+            argument_periapsis: 90.0,
+            star: StarPreset::sun()
         }
     ];
+}
+
+pub fn load_star_presets() -> Vec<StarPreset> {
+    vec![
+        StarPreset::sun(),
+        StarPreset {
+            name: String::from("TRAPPIST-1"),
+            m: 0.0898,
+            r: 0.1192,
+            l: 0.000553,
+            t_eff: 2566.0
+        },
+        StarPreset {
+            name: String::from("Proxima Centauri"),
+            m: 0.1221,
+            r: 0.1542,
+            l: 0.00151,
+            t_eff: 3042.0
+        },
+        // Both stars of the binary combined, radius and temperature of the primary
+        StarPreset {
+            name: String::from("Kepler-16 AB"),
+            m: 0.892,
+            r: 0.649,
+            l: 0.148,
+            t_eff: 4450.0
+        },
+        StarPreset {
+            name: String::from("HD 209458"),
+            m: 1.119,
+            r: 1.155,
+            l: 1.65,
+            t_eff: 6091.0
+        }
+    ]
+}
+
+// The geometry is (inclination, argument of periapsis)
+fn exoplanet(name: &str, m: f64, d: f64, a: f64, e: f64, geometry: (f64, f64), star: &StarPreset) -> PlanetPreset {
+    PlanetPreset {
+        name: String::from(name),
+        m,
+        d,
+        a,
+        e,
+        inclination: geometry.0,
+        argument_periapsis: geometry.1,
+        star: star.clone(),
+        ..Default::default()
+    }
+}
+
+pub fn load_exoplanet_presets() -> Vec<PlanetPreset> {
+    let stars = load_star_presets();
+    vec![
+        exoplanet("TRAPPIST-1 b", 1.374, 1.116, 0.01154, 0.006, (89.728, 90.0), &stars[1]),
+        exoplanet("TRAPPIST-1 c", 1.308, 1.097, 0.01580, 0.0066, (89.778, 90.0), &stars[1]),
+        exoplanet("TRAPPIST-1 d", 0.388, 0.788, 0.02227, 0.0084, (89.896, 90.0), &stars[1]),
+        exoplanet("TRAPPIST-1 e", 0.692, 0.920, 0.02925, 0.0051, (89.793, 90.0), &stars[1]),
+        exoplanet("TRAPPIST-1 f", 1.039, 1.045, 0.03849, 0.0101, (89.74, 90.0), &stars[1]),
+        exoplanet("TRAPPIST-1 g", 1.321, 1.129, 0.04683, 0.0021, (89.742, 90.0), &stars[1]),
+        exoplanet("TRAPPIST-1 h", 0.326, 0.755, 0.06189, 0.0057, (89.805, 90.0), &stars[1]),
+        // Minimum mass, the diameter is estimated and the inclination unknown as it does not transit
+        exoplanet("Proxima Centauri b", 1.07, 1.02, 0.04857, 0.02, (90.0, 90.0), &stars[2]),
+        exoplanet("Kepler-16 b", 105.8, 8.449, 0.7048, 0.0069, (90.0322, 318.0), &stars[3]),
+        exoplanet("HD 209458 b", 219.3, 15.47, 0.04707, 0.0, (86.71, 90.0), &stars[4])
+    ]
 }
\ No newline at end of file
@@ -0,0 +1,197 @@
+use std::f64::consts::PI;
+
+use crate::utils::{i18n::{fill_template, format_number, translate, Language}, units::{UnitSettings, AU, DAYS_PER_YEAR, EARTH_DIAMETER, EARTH_MASS, JUPITER_MASS, LIGHT_MINUTE, LIGHT_YEAR, MILE, PARSEC, SECONDS_PER_DAY, SOLAR_MASS}};
+
+// Quantities of the inputs, each with the unit the input stores its value in
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum QuantityKind {
+    // AU
+    Distance,
+    // Without unit, like the eccentricity
+    Ratio,
+    // Earth masses
+    Mass,
+    // Earth diameters
+    Diameter,
+    // Hours
+    Period,
+    // Years, for orbital periods
+    Time,
+    // km/s
+    Speed,
+    // Degrees
+    Angle,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum QuantityError {
+    NotANumber,
+    UnknownUnit(String),
+    // (min, max) of the allowed values
+    OutOfRange(f64, f64),
+}
+
+impl QuantityKind {
+    // Unit of the stored value, used when no unit is typed
+    pub fn unit(&self) -> &'static str {
+        match self {
+            QuantityKind::Distance => "AU",
+            QuantityKind::Ratio => "",
+            QuantityKind::Mass => "M🜨",
+            QuantityKind::Diameter => "D🜨",
+            QuantityKind::Period => "h",
+            QuantityKind::Time => "yr",
+            QuantityKind::Speed => "km/s",
+            QuantityKind::Angle => "°",
+        }
+    }
+
+    // Unit the inputs show for the unit settings and the factor from it to the unit of the stored value
+    pub fn display_unit(&self, units: UnitSettings) -> (&'static str, f64) {
+        match self {
+            QuantityKind::Distance => (units.distance_label(), 1.0 / units.distance(AU)),
+            QuantityKind::Time => (units.time_label(), 1.0 / units.time(1.0)),
+            QuantityKind::Speed => (units.speed_label(), 1.0 / units.speed(1.0)),
+            QuantityKind::Angle => (units.angle_label(), 1.0 / units.angle(1.0_f64.to_radians())),
+            _ => (self.unit(), 1.0),
+        }
+    }
+
+    // Factor from the typed unit to the unit of the stored value
+    fn factor(&self, unit: &str) -> Option<f64> {
+        if unit.is_empty() || unit == self.unit() {
+            return Some(1.0);
+        }
+
+        match (self, unit) {
+            (QuantityKind::Distance, "au" | "AE") => Some(1.0),
+            (QuantityKind::Distance, "m") => Some(1.0 / AU),
+            (QuantityKind::Distance, "km") => Some(1000.0 / AU),
+            (QuantityKind::Distance, "Mm") => Some(1e6 / AU),
+            (QuantityKind::Distance, "Gm") => Some(1e9 / AU),
+            (QuantityKind::Distance, "mi") => Some(MILE / AU),
+            (QuantityKind::Distance, "lmin" | "light-minutes" | "Lichtminuten") => Some(LIGHT_MINUTE / AU),
+            (QuantityKind::Distance, "ly" | "Lj") => Some(LIGHT_YEAR / AU),
+            (QuantityKind::Distance, "pc") => Some(PARSEC / AU),
+            (QuantityKind::Ratio, "%") => Some(0.01),
+            (QuantityKind::Mass, "kg") => Some(1.0 / EARTH_MASS),
+            (QuantityKind::Mass, "t") => Some(1000.0 / EARTH_MASS),
+            (QuantityKind::Mass, "ME" | "M⊕" | "Mearth" | "earth") => Some(1.0),
+            (QuantityKind::Mass, "MJ" | "Mjup") => Some(JUPITER_MASS / EARTH_MASS),
+            (QuantityKind::Mass, "M☉" | "Msun") => Some(SOLAR_MASS / EARTH_MASS),
+            (QuantityKind::Diameter, "m") => Some(0.001 / EARTH_DIAMETER),
+            (QuantityKind::Diameter, "km") => Some(1.0 / EARTH_DIAMETER),
+            (QuantityKind::Diameter, "mi") => Some(MILE / 1000.0 / EARTH_DIAMETER),
+            (QuantityKind::Diameter, "DE" | "D⊕" | "earth") => Some(1.0),
+            (QuantityKind::Period, "s") => Some(1.0 / 3600.0),
+            (QuantityKind::Period, "min") => Some(1.0 / 60.0),
+            (QuantityKind::Period, "d" | "days" | "Tage") => Some(24.0),
+            (QuantityKind::Time, "y" | "a" | "years" | "Jahre") => Some(1.0),
+            (QuantityKind::Time, "d" | "days" | "Tage") => Some(1.0 / DAYS_PER_YEAR),
+            (QuantityKind::Time, "h") => Some(1.0 / DAYS_PER_YEAR / 24.0),
+            (QuantityKind::Time, "s") => Some(1.0 / DAYS_PER_YEAR / SECONDS_PER_DAY),
+            (QuantityKind::Speed, "m/s") => Some(0.001),
+            (QuantityKind::Speed, "km/h") => Some(1.0 / 3600.0),
+            (QuantityKind::Speed, "mi/s") => Some(MILE / 1000.0),
+            (QuantityKind::Speed, "AU/d" | "AU/day" | "AE/Tag") => Some(AU / 1000.0 / SECONDS_PER_DAY),
+            (QuantityKind::Angle, "deg") => Some(1.0),
+            (QuantityKind::Angle, "rad") => Some(180.0 / PI),
+            _ => None,
+        }
+    }
+}
+
+impl QuantityError {
+    pub fn message(&self, language: Language) -> String {
+        match self {
+            QuantityError::NotANumber => String::from(translate(language, "Not a number")),
+            QuantityError::UnknownUnit(unit) => fill_template(translate(language, "Unknown unit {}"), std::slice::from_ref(unit)),
+            QuantityError::OutOfRange(min, max) if max.is_infinite() => {
+                fill_template(translate(language, "The value has to be at least {}"), &[format_value(*min, language)])
+            },
+            QuantityError::OutOfRange(min, max) => {
+                fill_template(translate(language, "The value has to be between {} and {}"), &[format_value(*min, language), format_value(*max, language)])
+            },
+        }
+    }
+}
+
+// Format a value so that parse_quantity reads it back, with six significant digits
+pub fn format_value(value: f64, language: Language) -> String {
+    // log10 of zero is infinite and has no number of decimals
+    if value == 0.0 || !value.is_finite() {
+        return format_number(language, value, 0);
+    }
+
+    // Without thousands separators, which would read as a decimal separator in the other language
+    if (1e-3..1e6).contains(&value.abs()) {
+        let decimals = (5 - value.abs().log10().floor() as i32).clamp(0, 8) as usize;
+        let formatted = format!("{:.*}", decimals, value);
+        let formatted = if formatted.contains('.') { formatted.trim_end_matches('0').trim_end_matches('.') } else { &formatted };
+        formatted.replace('.', &language.separators().0.to_string())
+    } else {
+        let formatted = format!("{:.5e}", value);
+        let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+        format!("{}e{}", format_value(mantissa.parse::<f64>().unwrap_or(0.0), language), exponent)
+    }
+}
+
+// Value with the unit of the unit settings, so that parse_quantity reads it back
+pub fn format_quantity(value: f64, kind: QuantityKind, units: UnitSettings, language: Language) -> String {
+    let (unit, factor) = kind.display_unit(units);
+    format!("{} {}", format_value(value / factor, language), translate(language, unit)).trim().to_string()
+}
+
+// Whether the separator groups the leading number into thousands, like "57.900" or "1,234,567"
+fn has_thousands_groups(text: &str, separator: char) -> bool {
+    let number: String = text
+        .trim_start_matches(['-', '+'])
+        .chars()
+        .take_while(|character| character.is_ascii_digit() || *character == separator)
+        .collect();
+    let mut groups = number.split(separator);
+    let first = groups.next().unwrap_or_default();
+    number.contains(separator) && (1..=3).contains(&first.len()) && !first.starts_with('0') && groups.all(|group| group.len() == 3)
+}
+
+// Remove thousands separators and use a decimal point, the last of "." and "," is the decimal separator
+// A single kind of separator is a thousands separator only if the language uses it so and it groups by three digits
+fn normalize_separators(text: &str, language: Language) -> String {
+    let (_, thousands) = language.separators();
+    let decimal = match (text.rfind('.'), text.rfind(',')) {
+        (Some(point), Some(comma)) => if point > comma { '.' } else { ',' },
+        (Some(_), None) => if thousands == '.' && has_thousands_groups(text, '.') { ',' } else { '.' },
+        (None, Some(_)) => if thousands == ',' && has_thousands_groups(text, ',') { '.' } else { ',' },
+        (None, None) => '.',
+    };
+    text.chars()
+        .filter(|character| *character == decimal || (*character != '.' && *character != ','))
+        .map(|character| if character == ',' { '.' } else { character })
+        .collect()
+}
+
+// Parse a number with an optional unit, like "57.9e6 km", into the unit of the quantity
+// Without a unit the number is in the unit of the unit settings, the range is given in the unit of the quantity
+pub fn parse_quantity(text: &str, kind: QuantityKind, (min, max): (f64, f64), units: UnitSettings, language: Language) -> Result<f64, QuantityError> {
+    let text = normalize_separators(text.trim(), language);
+
+    // The longest start of the text that is a number, the rest is the unit
+    let (value, unit) = text
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(text.len()))
+        .rev()
+        .find_map(|index| text[..index].trim().parse::<f64>().ok().map(|value| (value, text[index..].trim())))
+        .ok_or(QuantityError::NotANumber)?;
+
+    let (_, display_factor) = kind.display_unit(units);
+    let factor = if unit.is_empty() { Some(display_factor) } else { kind.factor(unit) };
+    let value = value * factor.ok_or_else(|| QuantityError::UnknownUnit(String::from(unit)))?;
+
+    if value.is_finite() && (min..=max).contains(&value) {
+        Ok(value)
+    } else {
+        // The range is shown in the unit of the input
+        Err(QuantityError::OutOfRange(min / display_factor, max / display_factor))
+    }
+}
\ No newline at end of file
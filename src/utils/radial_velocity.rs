@@ -0,0 +1,16 @@
+use std::f64::consts::PI;
+
+use crate::utils::units::{DAYS_PER_YEAR, SECONDS_PER_DAY};
+
+// Calculate the semi-amplitude of the radial velocity of the star in m/s
+pub fn calculate_radial_velocity_amplitude(m_object_earth_ratio: f64, m_earth: f64, m_sun: f64, g: f64, period_years: f64, e: f64, inclination: f64) -> f64 {
+    let m_object_kg = m_object_earth_ratio * m_earth;
+    let period_seconds = period_years * DAYS_PER_YEAR * SECONDS_PER_DAY;
+
+    f64::cbrt(2.0 * PI * g / period_seconds) * m_object_kg * inclination.sin() / (m_sun + m_object_kg).powf(2.0 / 3.0) / f64::sqrt(1.0 - e * e)
+}
+
+// Calculate the radial velocity of the star (positive when moving away from the observer) at a true anomaly of the object
+pub fn calculate_radial_velocity(amplitude: f64, e: f64, argument_periapsis: f64, angle: f64) -> f64 {
+    amplitude * ((angle + argument_periapsis).cos() + e * argument_periapsis.cos())
+}
@@ -0,0 +1,30 @@
+// Standard gravity used to convert a specific impulse in s to an exhaust velocity
+const G0: f64 = 9.80665;
+
+// Calculate the ratio of initial to final mass needed for a Δv in m/s (Tsiolkovsky rocket equation)
+pub fn calculate_mass_ratio(delta_v: f64, isp: f64) -> f64 {
+    f64::exp(delta_v / (isp * G0))
+}
+
+// Calculate the Δv in m/s of a stage from its initial and final mass
+pub fn calculate_delta_v(isp: f64, m_initial: f64, m_final: f64) -> f64 {
+    isp * G0 * (m_initial / m_final).ln()
+}
+
+// Calculate the part of the initial mass that has to be propellant
+pub fn calculate_propellant_fraction(delta_v: f64, isp: f64) -> f64 {
+    1.0 - 1.0 / calculate_mass_ratio(delta_v, isp)
+}
+
+// Calculate the payload fraction of a rocket with identical stages sharing the Δv equally,
+// the structural ratio is the dry mass of a stage divided by its dry plus propellant mass
+pub fn calculate_payload_fraction(delta_v: f64, isp: f64, structural_ratio: f64, stages: u32) -> f64 {
+    let stage_mass_ratio = calculate_mass_ratio(delta_v / stages as f64, isp);
+    let stage_fraction = 1.0 - (1.0 - 1.0 / stage_mass_ratio) / (1.0 - structural_ratio);
+
+    if stage_fraction > 0.0 {
+        stage_fraction.powi(stages as i32)
+    } else {
+        0.0
+    }
+}
\ No newline at end of file
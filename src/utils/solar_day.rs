@@ -0,0 +1,16 @@
+use crate::utils::units::DAYS_PER_YEAR;
+
+// Calculate the length of a solar day in hours from the sidereal rotation and the orbital period
+pub fn calculate_solar_day(rotation_period: f64, axial_tilt: f64, orbital_period_years: f64) -> f64 {
+    let orbital_period_hours = orbital_period_years * DAYS_PER_YEAR * 24.0;
+    // Retrograde rotators (tilt above 90°) turn against their orbital motion
+    let direction = if axial_tilt > 90.0 { -1.0 } else { 1.0 };
+    let divisor = 1.0 - direction * rotation_period / orbital_period_hours;
+
+    // Tidally locked bodies never see the sun move
+    if divisor.abs() < 1e-9 {
+        return f64::INFINITY;
+    }
+
+    (rotation_period / divisor).abs()
+}
@@ -0,0 +1,150 @@
+use std::f64::consts::PI;
+
+// Below this the orbit counts as circular or equatorial
+const TOLERANCE: f64 = 1e-10;
+
+// Frames of the state vector, both centered on the star
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Frame {
+    // x towards the periapsis, z along the angular momentum
+    Perifocal,
+    // x towards the ascending node (Ω = 0) or the reference direction, z perpendicular to the reference plane like the sky
+    Reference,
+}
+
+// Position (m) and velocity (m/s) of the object
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct StateVector {
+    pub position: [f64; 3],
+    pub velocity: [f64; 3],
+    pub frame: Frame,
+}
+
+// Classical Keplerian elements, angles in rad
+// The orbit size is the semi-latus rectum p (m), which stays finite for parabolic orbits unlike a
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct KeplerianElements {
+    pub p: f64,
+    pub e: f64,
+    pub inclination: f64,
+    pub longitude_ascending_node: f64,
+    pub argument_periapsis: f64,
+    pub true_anomaly: f64,
+}
+
+impl KeplerianElements {
+    // Semi-major axis, negative for hyperbolic orbits and infinite for parabolic ones
+    pub fn a(&self) -> f64 {
+        if self.e == 1.0 {
+            f64::INFINITY
+        } else {
+            self.p / (1.0 - self.e * self.e)
+        }
+    }
+}
+
+fn dot(u: [f64; 3], v: [f64; 3]) -> f64 {
+    u[0] * v[0] + u[1] * v[1] + u[2] * v[2]
+}
+
+fn cross(u: [f64; 3], v: [f64; 3]) -> [f64; 3] {
+    [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]]
+}
+
+fn norm(u: [f64; 3]) -> f64 {
+    dot(u, u).sqrt()
+}
+
+// Angle between two vectors from 0 to PI
+fn angle_between(u: [f64; 3], v: [f64; 3]) -> f64 {
+    (dot(u, v) / (norm(u) * norm(v))).clamp(-1.0, 1.0).acos()
+}
+
+// Rotate a perifocal vector into the reference frame, R3(-Ω) R1(-i) R3(-ω)
+fn perifocal_to_reference(u: [f64; 3], elements: &KeplerianElements) -> [f64; 3] {
+    let (sin_o, cos_o) = elements.longitude_ascending_node.sin_cos();
+    let (sin_i, cos_i) = elements.inclination.sin_cos();
+    let (sin_w, cos_w) = elements.argument_periapsis.sin_cos();
+
+    let x = [cos_o * cos_w - sin_o * sin_w * cos_i, -cos_o * sin_w - sin_o * cos_w * cos_i, sin_o * sin_i];
+    let y = [sin_o * cos_w + cos_o * sin_w * cos_i, -sin_o * sin_w + cos_o * cos_w * cos_i, -cos_o * sin_i];
+    let z = [sin_w * sin_i, cos_w * sin_i, cos_i];
+    [dot(x, u), dot(y, u), dot(z, u)]
+}
+
+// State vector of the elements with the gravitational parameter mu = G * M (m³/s²)
+pub fn state_vector_from_elements(elements: &KeplerianElements, mu: f64, frame: Frame) -> StateVector {
+    let e = elements.e;
+    let (sin_v, cos_v) = elements.true_anomaly.sin_cos();
+    let p = elements.p;
+    let radius = p / (1.0 + e * cos_v);
+    let speed = (mu / p).sqrt();
+
+    let position = [radius * cos_v, radius * sin_v, 0.0];
+    let velocity = [-speed * sin_v, speed * (e + cos_v), 0.0];
+
+    match frame {
+        Frame::Perifocal => StateVector { position, velocity, frame },
+        Frame::Reference => StateVector {
+            position: perifocal_to_reference(position, elements),
+            velocity: perifocal_to_reference(velocity, elements),
+            frame,
+        },
+    }
+}
+
+// Keplerian elements of a state vector relative to its frame with the gravitational parameter mu = G * M (m³/s²)
+// Circular orbits have no periapsis, ω is 0 and ν is measured from the ascending node
+// Equatorial orbits have no ascending node, Ω is 0 and ω is measured from the x axis
+pub fn elements_from_state_vector(state: &StateVector, mu: f64) -> KeplerianElements {
+    let r = state.position;
+    let v = state.velocity;
+    let radius = norm(r);
+    let speed = norm(v);
+
+    let h = cross(r, v);
+    let node = cross([0.0, 0.0, 1.0], h);
+    let radial_speed = dot(r, v) / radius;
+    let eccentricity_vector = [0, 1, 2].map(|k| ((speed * speed - mu / radius) * r[k] - radius * radial_speed * v[k]) / mu);
+    let e = norm(eccentricity_vector);
+
+    let p = dot(h, h) / mu;
+    let inclination = (h[2] / norm(h)).clamp(-1.0, 1.0).acos();
+
+    let circular = e < TOLERANCE;
+    let equatorial = norm(node) < TOLERANCE * norm(h);
+    // Angles measured in the direction of motion, which is clockwise seen from +z for retrograde orbits
+    let prograde = h[2] >= 0.0;
+    let longitude = |u: [f64; 3]| {
+        let angle = u[1].atan2(u[0]);
+        if prograde { angle } else { -angle }.rem_euclid(2.0 * PI)
+    };
+
+    let longitude_ascending_node = if equatorial { 0.0 } else { node[1].atan2(node[0]).rem_euclid(2.0 * PI) };
+
+    let argument_periapsis = match (circular, equatorial) {
+        (true, _) => 0.0,
+        // Longitude of the periapsis
+        (false, true) => longitude(eccentricity_vector),
+        (false, false) => {
+            let angle = angle_between(node, eccentricity_vector);
+            if eccentricity_vector[2] < 0.0 { 2.0 * PI - angle } else { angle }
+        },
+    };
+
+    let true_anomaly = match (circular, equatorial) {
+        // True longitude
+        (true, true) => longitude(r),
+        // Argument of latitude
+        (true, false) => {
+            let angle = angle_between(node, r);
+            if r[2] < 0.0 { 2.0 * PI - angle } else { angle }
+        },
+        (false, _) => {
+            let angle = angle_between(eccentricity_vector, r);
+            if radial_speed < 0.0 { 2.0 * PI - angle } else { angle }
+        },
+    };
+
+    KeplerianElements { p, e, inclination, longitude_ascending_node, argument_periapsis, true_anomaly }
+}
\ No newline at end of file
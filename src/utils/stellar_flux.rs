@@ -0,0 +1,13 @@
+use std::f64::consts::PI;
+
+// Calculate the flux of the host star received at the semi-major axis in W/m²
+pub fn calculate_stellar_flux(l_sun: f64, a: f64) -> f64 {
+    l_sun / (4.0 * PI * a.powi(2))
+}
+
+// Calculate the equilibrium temperature of an object with the given bond albedo, assuming the heat is spread over the whole surface
+pub fn calculate_equilibrium_temperature(l_sun: f64, a: f64, albedo: f64) -> f64 {
+    let stefan_boltzmann = 5.670374419 * 10.0_f64.powi(-8);
+
+    f64::powf(calculate_stellar_flux(l_sun, a) * (1.0 - albedo) / (4.0 * stefan_boltzmann), 0.25)
+}
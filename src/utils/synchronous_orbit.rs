@@ -0,0 +1,9 @@
+use std::f64::consts::PI;
+
+// Calculate the radius of an orbit whose period equals the rotation period of the object (geostationary-equivalent)
+pub fn calculate_synchronous_orbit_radius(rotation_period: f64, m_object_earth_ratio: f64, m_earth: f64, g: f64) -> f64 {
+    let m_object_kg = m_object_earth_ratio * m_earth;
+    let period_seconds = rotation_period * 60.0 * 60.0;
+
+    f64::cbrt(g * m_object_kg * period_seconds.powi(2) / (4.0 * PI * PI))
+}
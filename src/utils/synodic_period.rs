@@ -0,0 +1,4 @@
+// Calculate the synodic period (time between two alignments) of two objects orbiting the same star
+pub fn calculate_synodic_period(period_1: f64, period_2: f64) -> f64 {
+    1.0 / (1.0 / period_1 - 1.0 / period_2).abs()
+}
@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use web_sys::window;
+use log::info;
+
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum Theme {
+    // Light or dark, following the prefers-color-scheme of the system
+    #[default]
+    System,
+    Light,
+    Dark,
+    HighContrast,
+    Print,
+}
+
+// Colours of the canvas drawings, the background comes from the CSS of the theme
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CanvasPalette {
+    pub orbit: &'static str,
+    // Grid lines, their labels and the precession rosette
+    pub grid: &'static str,
+    pub stored_orbits: &'static str,
+    pub comparison: &'static str,
+    pub probe: &'static str,
+    pub lagrange_points: &'static str,
+    pub flyby_in: &'static str,
+    pub flyby_out: &'static str,
+    pub star: &'static str,
+    pub object: &'static str,
+}
+
+impl Theme {
+    // Theme actually shown, System is resolved with the prefers-color-scheme media query
+    pub fn resolved(&self) -> Theme {
+        match self {
+            Theme::System => {
+                let prefers_light = window()
+                    .and_then(|win| win.match_media("(prefers-color-scheme: light)").ok().flatten())
+                    .is_some_and(|query| query.matches());
+                if prefers_light { Theme::Light } else { Theme::Dark }
+            },
+            theme => *theme,
+        }
+    }
+
+    // Value of the data-theme attribute of the document
+    pub fn code(&self) -> &'static str {
+        match self.resolved() {
+            Theme::Light => "light",
+            Theme::HighContrast => "high-contrast",
+            Theme::Print => "print",
+            _ => "dark",
+        }
+    }
+
+    pub fn palette(&self) -> CanvasPalette {
+        match self.resolved() {
+            Theme::Light => CanvasPalette {
+                orbit: "#1b1921",
+                grid: "#4f5b66",
+                stored_orbits: "green",
+                comparison: "purple",
+                probe: "crimson",
+                lagrange_points: "darkgoldenrod",
+                flyby_in: "steelblue",
+                flyby_out: "darkorange",
+                star: "orange",
+                object: "steelblue",
+            },
+            Theme::HighContrast => CanvasPalette {
+                orbit: "white",
+                grid: "white",
+                stored_orbits: "lime",
+                comparison: "magenta",
+                probe: "red",
+                lagrange_points: "yellow",
+                flyby_in: "cyan",
+                flyby_out: "orange",
+                star: "yellow",
+                object: "cyan",
+            },
+            Theme::Print => CanvasPalette {
+                orbit: "black",
+                grid: "black",
+                stored_orbits: "dimgray",
+                comparison: "gray",
+                probe: "black",
+                lagrange_points: "black",
+                flyby_in: "dimgray",
+                flyby_out: "black",
+                star: "black",
+                object: "dimgray",
+            },
+            _ => CanvasPalette {
+                orbit: "white",
+                grid: "white",
+                stored_orbits: "lime",
+                comparison: "violet",
+                probe: "red",
+                lagrange_points: "gold",
+                flyby_in: "deepskyblue",
+                flyby_out: "orange",
+                star: "yellow",
+                object: "deepskyblue",
+            },
+        }
+    }
+}
+
+pub fn save_theme(theme: Theme) {
+    if let Some(win) = window() {
+        if let Ok(Some(storage)) = win.local_storage() {
+            storage.set_item("theme", &serde_json::to_string(&theme).unwrap()).unwrap();
+        }
+    }
+}
+
+pub fn load_theme() -> Theme {
+    if let Some(win) = window() {
+        if let Ok(Some(storage)) = win.local_storage() {
+            if let Ok(Some(stored_data)) = storage.get_item("theme") {
+                match serde_json::from_str::<Theme>(&stored_data) {
+                    Ok(theme) => return theme,
+                    Err(e) => info!("Error deserializing data: {}", e),
+                }
+            }
+        }
+    }
+    Theme::default()
+}
\ No newline at end of file
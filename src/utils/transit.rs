@@ -0,0 +1,56 @@
+use std::f64::consts::PI;
+
+// Quadratic limb darkening coefficients of a sun-like star
+pub const LIMB_DARKENING: (f64, f64) = (0.40, 0.26);
+
+// Intensity of the stellar disk at a distance from its centre (in stellar radii) relative to the centre
+fn limb_darkened_intensity(distance: f64, limb_darkening: (f64, f64)) -> f64 {
+    let mu = f64::sqrt(1.0 - distance.powi(2));
+    1.0 - limb_darkening.0 * (1.0 - mu) - limb_darkening.1 * (1.0 - mu).powi(2)
+}
+
+// Area of the stellar disk (radius 1) covered by a planet of radius ratio p at a projected distance z from its centre
+fn calculate_overlap_area(z: f64, p: f64) -> f64 {
+    if z >= 1.0 + p {
+        0.0
+    } else if z <= 1.0 - p {
+        PI * p * p
+    } else if z <= p - 1.0 {
+        PI
+    } else {
+        let planet_angle = ((p * p + z * z - 1.0) / (2.0 * p * z)).clamp(-1.0, 1.0).acos();
+        let star_angle = ((1.0 - p * p + z * z) / (2.0 * z)).clamp(-1.0, 1.0).acos();
+        p * p * planet_angle + star_angle - 0.5 * f64::sqrt((4.0 * z * z - (1.0 + z * z - p * p).powi(2)).max(0.0))
+    }
+}
+
+// Integral of the intensity over the stellar disk outside of a distance from the centre, divided by PI
+fn integrate_intensity_outside(distance: f64, limb_darkening: (f64, f64)) -> f64 {
+    let mu = f64::sqrt((1.0 - distance * distance).max(0.0));
+    mu.powi(2)
+        - limb_darkening.0 * (mu.powi(2) - 2.0 * mu.powi(3) / 3.0)
+        - limb_darkening.1 * (mu.powi(2) - 4.0 * mu.powi(3) / 3.0 + mu.powi(4) / 2.0)
+}
+
+// Calculate the flux of the star relative to its unobstructed flux while a planet of radius ratio p is at
+// a projected distance z from the centre of the star (both in stellar radii), from the covered area of a uniform disk
+// times the mean intensity of the ring of the star the planet lies on
+pub fn calculate_transit_flux(z: f64, p: f64, limb_darkening: (f64, f64)) -> f64 {
+    if z >= 1.0 + p {
+        return 1.0;
+    }
+
+    let total = 1.0 - limb_darkening.0 / 3.0 - limb_darkening.1 / 6.0;
+    let (inner, outer) = ((z - p).max(0.0), (z + p).min(1.0));
+    let intensity = if outer > inner {
+        (integrate_intensity_outside(inner, limb_darkening) - integrate_intensity_outside(outer, limb_darkening)) / (outer * outer - inner * inner)
+    } else {
+        limb_darkened_intensity(z.min(1.0), limb_darkening)
+    };
+    1.0 - calculate_overlap_area(z, p) / PI * intensity / total
+}
+
+// Calculate the impact parameter (closest projected distance during transit in stellar radii)
+pub fn calculate_impact_parameter(a: f64, e: f64, inclination: f64, argument_periapsis: f64, r_sun: f64) -> f64 {
+    a * inclination.cos().abs() / r_sun * (1.0 - e * e) / (1.0 + e * argument_periapsis.sin())
+}
@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+use web_sys::window;
+use log::info;
+
+use crate::utils::i18n::{format_number, format_scientific, translate, Language};
+
+// Meters in an astronomical unit
+pub const AU: f64 = 1.496e11;
+// Meters light travels in a minute
+pub const LIGHT_MINUTE: f64 = 299_792_458.0 * 60.0;
+pub const DAYS_PER_YEAR: f64 = 365.25;
+pub const SECONDS_PER_DAY: f64 = 86_400.0;
+pub const MILE: f64 = 1609.344;
+pub const FOOT: f64 = 0.3048;
+// Newtons in a pound-force
+pub const POUND_FORCE: f64 = 4.448_221_615_260_5;
+pub const STANDARD_GRAVITY: f64 = 9.80665;
+// m³/(kg s²)
+pub const GRAVITATIONAL_CONSTANT: f64 = 6.67430e-11;
+// Kilograms
+pub const EARTH_MASS: f64 = 5.972168e24;
+// Kilometers
+pub const EARTH_DIAMETER: f64 = 12742.46;
+pub const JUPITER_MASS: f64 = 1.898125e27;
+pub const SOLAR_MASS: f64 = 1.988416e30;
+// Meters
+pub const SOLAR_RADIUS: f64 = 6.957e8;
+// Watts
+pub const SOLAR_LUMINOSITY: f64 = 3.828e26;
+pub const LIGHT_YEAR: f64 = 9.460_730_472_580_8e15;
+pub const PARSEC: f64 = 3.085_677_581e16;
+
+// Units of speeds, accelerations, forces and lengths close to the object
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum UnitSystem {
+    #[default]
+    Si,
+    Astronomical,
+    Imperial,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum AngleUnit {
+    #[default]
+    Radians,
+    Degrees,
+}
+
+// Units of distances along the orbit
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum DistanceUnit {
+    #[default]
+    AstronomicalUnits,
+    Kilometers,
+    LightMinutes,
+}
+
+// Units of periods
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum TimeUnit {
+    Days,
+    #[default]
+    Years,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct UnitSettings {
+    pub system: UnitSystem,
+    pub angle: AngleUnit,
+    pub distance: DistanceUnit,
+    pub time: TimeUnit,
+}
+
+impl UnitSettings {
+    // Distance along the orbit from m
+    pub fn distance(&self, meters: f64) -> f64 {
+        match self.distance {
+            DistanceUnit::AstronomicalUnits => meters / AU,
+            DistanceUnit::Kilometers => meters / 1000.0,
+            DistanceUnit::LightMinutes => meters / LIGHT_MINUTE,
+        }
+    }
+
+    pub fn distance_label(&self) -> &'static str {
+        match self.distance {
+            DistanceUnit::AstronomicalUnits => "AU",
+            DistanceUnit::Kilometers => "km",
+            DistanceUnit::LightMinutes => "light-minutes",
+        }
+    }
+
+    pub fn format_distance(&self, meters: f64, language: Language) -> String {
+        let value = match self.distance {
+            DistanceUnit::Kilometers => format_scientific(language, self.distance(meters), 3),
+            _ => format_number(language, self.distance(meters), 2),
+        };
+        format!("{} {}", value, translate(language, self.distance_label()))
+    }
+
+    // Angle from rad
+    pub fn angle(&self, radians: f64) -> f64 {
+        match self.angle {
+            AngleUnit::Radians => radians,
+            AngleUnit::Degrees => radians.to_degrees(),
+        }
+    }
+
+    // Angle in rad from the chosen unit
+    pub fn parse_angle(&self, value: f64) -> f64 {
+        match self.angle {
+            AngleUnit::Radians => value,
+            AngleUnit::Degrees => value.to_radians(),
+        }
+    }
+
+    pub fn angle_label(&self) -> &'static str {
+        match self.angle {
+            AngleUnit::Radians => "rad",
+            AngleUnit::Degrees => "°",
+        }
+    }
+
+    pub fn format_angle(&self, radians: f64, language: Language) -> String {
+        match self.angle {
+            AngleUnit::Radians => format!("{} rad", format_number(language, radians, 2)),
+            AngleUnit::Degrees => format!("{}°", format_number(language, radians.to_degrees(), 1)),
+        }
+    }
+
+    // Period from years
+    pub fn time(&self, years: f64) -> f64 {
+        match self.time {
+            TimeUnit::Days => years * DAYS_PER_YEAR,
+            TimeUnit::Years => years,
+        }
+    }
+
+    pub fn time_label(&self) -> &'static str {
+        match self.time {
+            TimeUnit::Days => "days",
+            TimeUnit::Years => "years",
+        }
+    }
+
+    pub fn format_time(&self, years: f64, language: Language) -> String {
+        format!("{} {}", format_number(language, self.time(years), 2), translate(language, self.time_label()))
+    }
+
+    // Speed from km/s
+    pub fn speed(&self, km_per_second: f64) -> f64 {
+        match self.system {
+            UnitSystem::Si => km_per_second,
+            UnitSystem::Astronomical => km_per_second * 1000.0 * SECONDS_PER_DAY / AU,
+            UnitSystem::Imperial => km_per_second * 1000.0 / MILE,
+        }
+    }
+
+    // Speed in km/s from the chosen unit
+    pub fn parse_speed(&self, value: f64) -> f64 {
+        match self.system {
+            UnitSystem::Si => value,
+            UnitSystem::Astronomical => value * AU / SECONDS_PER_DAY / 1000.0,
+            UnitSystem::Imperial => value * MILE / 1000.0,
+        }
+    }
+
+    pub fn speed_label(&self) -> &'static str {
+        match self.system {
+            UnitSystem::Si => "km/s",
+            UnitSystem::Astronomical => "AU/day",
+            UnitSystem::Imperial => "mi/s",
+        }
+    }
+
+    pub fn format_speed(&self, km_per_second: f64, language: Language) -> String {
+        let decimals = if self.system == UnitSystem::Astronomical { 5 } else { 3 };
+        format!("{} {}", format_number(language, self.speed(km_per_second), decimals), translate(language, self.speed_label()))
+    }
+
+    // Acceleration from m/s²
+    pub fn acceleration(&self, meters_per_second_squared: f64) -> f64 {
+        match self.system {
+            UnitSystem::Si => meters_per_second_squared,
+            UnitSystem::Astronomical => meters_per_second_squared / STANDARD_GRAVITY,
+            UnitSystem::Imperial => meters_per_second_squared / FOOT,
+        }
+    }
+
+    pub fn acceleration_label(&self) -> &'static str {
+        match self.system {
+            UnitSystem::Si => "m/(s²)",
+            UnitSystem::Astronomical => "g",
+            UnitSystem::Imperial => "ft/(s²)",
+        }
+    }
+
+    pub fn format_acceleration(&self, meters_per_second_squared: f64, language: Language) -> String {
+        format!("{} {}", format_number(language, self.acceleration(meters_per_second_squared), 4), self.acceleration_label())
+    }
+
+    // Force from N
+    pub fn force(&self, newtons: f64) -> f64 {
+        match self.system {
+            UnitSystem::Imperial => newtons / POUND_FORCE,
+            _ => newtons,
+        }
+    }
+
+    pub fn force_label(&self) -> &'static str {
+        match self.system {
+            UnitSystem::Imperial => "lbf",
+            _ => "N",
+        }
+    }
+
+    pub fn format_force(&self, newtons: f64, language: Language) -> String {
+        format!("{} {}", format_scientific(language, self.force(newtons), 3), self.force_label())
+    }
+
+    // Length close to the object, like an altitude, from km
+    pub fn length(&self, kilometers: f64) -> f64 {
+        match self.system {
+            UnitSystem::Imperial => kilometers * 1000.0 / MILE,
+            _ => kilometers,
+        }
+    }
+
+    // Length close to the object in km from the chosen unit
+    pub fn parse_length(&self, value: f64) -> f64 {
+        match self.system {
+            UnitSystem::Imperial => value * MILE / 1000.0,
+            _ => value,
+        }
+    }
+
+    pub fn length_label(&self) -> &'static str {
+        match self.system {
+            UnitSystem::Imperial => "mi",
+            _ => "km",
+        }
+    }
+}
+
+pub fn save_unit_settings(settings: &UnitSettings) {
+    if let Some(win) = window() {
+        if let Ok(Some(storage)) = win.local_storage() {
+            storage.set_item("unit_settings", &serde_json::to_string(settings).unwrap()).unwrap();
+        }
+    }
+}
+
+pub fn load_unit_settings() -> UnitSettings {
+    if let Some(win) = window() {
+        if let Ok(Some(storage)) = win.local_storage() {
+            if let Ok(Some(stored_data)) = storage.get_item("unit_settings") {
+                match serde_json::from_str::<UnitSettings>(&stored_data) {
+                    Ok(settings) => return settings,
+                    Err(e) => info!("Error deserializing data: {}", e),
+                }
+            }
+        }
+    }
+    UnitSettings::default()
+}
\ No newline at end of file